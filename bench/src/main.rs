@@ -28,6 +28,8 @@ macro_rules! time{
 
 pub struct Stats<'a> {
     label: &'a str,
+    runs: u128,
+    bytes: u128,
     min: u128,
     max: u128,
     mean: u128,
@@ -37,8 +39,17 @@ impl<'a> core::fmt::Display for Stats<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!(
             "Benchmark {} ({} iterations): min - {}, max - {}, mean - {}",
-            self.label, RUNS, self.min, self.max, self.mean
-        ))
+            self.label, self.runs, self.min, self.max, self.mean
+        ))?;
+        if self.bytes != 0 {
+            // `min` is the time for `WRITE_COUNT` messages of `bytes` bytes, and a byte per nanosecond is a GB/s
+            f.write_fmt(format_args!(
+                " ({:.1} ns per message, {:.2} GB/s)",
+                self.min as f64 / WRITE_COUNT as f64,
+                (self.bytes * WRITE_COUNT) as f64 / self.min as f64
+            ))?;
+        }
+        Ok(())
     }
 }
 
@@ -93,6 +104,22 @@ pub fn raw_siphash<const C: usize, const D: usize>() -> u128 {
     }
 }
 
+/// Hashes a message of `N` bytes from a new hasher that uses the backend `B`, for each iteration
+pub fn message<const C: usize, const D: usize, B: lccc_siphash::SipBackend, const N: usize>() -> u128
+{
+    use core::hash::Hasher;
+    let msg = core::hint::black_box([0x5a; N]);
+    let k0 = core::hint::black_box(0x6a09e667f3bcc908);
+    let k1 = core::hint::black_box(0xbb67ae8584caa73b);
+    time! {
+        loop{
+            let mut hasher = lccc_siphash::SipHasher::<C, D, B>::new_with_backend_keys(k0, k1);
+            hasher.write(core::hint::black_box(&msg));
+            core::hint::black_box(hasher.finish());
+        }
+    }
+}
+
 pub fn std_hasher() -> u128 {
     use core::hash::Hasher;
     let ikey = core::hint::black_box(0x428a2f98d728ae22);
@@ -106,14 +133,25 @@ pub fn std_hasher() -> u128 {
     }
 }
 
-pub fn run_benchmark(label: &str, bench_fn: fn() -> u128) -> Stats {
+/// A benchmark: its label, the length of the message it hashes for each iteration (or 0), and the function that runs it
+type Benchmark = (&'static str, usize, fn() -> u128);
+
+/// Runs `bench_fn`, which hashes a message of `bytes` bytes for each iteration, or performs some other operation if `bytes` is 0.
+/// The throughput is reported for the benchmarks that hash messages.
+pub fn run_benchmark(label: &str, bytes: usize, bench_fn: fn() -> u128) -> Stats<'_> {
+    let runs = if bytes == 0 {
+        RUNS
+    } else {
+        // Scale the runs down for long messages, so each benchmark takes a similar time
+        (RUNS * 8 / (bytes as u128) / 64).max(100)
+    };
     let mut min = !0;
     let mut max = 0;
     let mut total = 0;
 
-    println!("Benchmarking {} ({} Iterations)", label, RUNS);
+    println!("Benchmarking {} ({} Iterations)", label, runs);
 
-    for _ in 0..RUNS {
+    for _ in 0..runs {
         let val = bench_fn();
         total += val;
         min = min.min(val);
@@ -122,24 +160,54 @@ pub fn run_benchmark(label: &str, bench_fn: fn() -> u128) -> Stats {
 
     Stats {
         label,
+        runs,
+        bytes: bytes as u128,
         min,
         max,
-        mean: total / RUNS,
+        mean: total / runs,
     }
 }
 
 fn main() {
-    let benches = [
-        run_benchmark("Baseline", baseline),
-        run_benchmark("Sipround", sipround),
-        run_benchmark("SipHash-1-3", siphash::<1, 3>),
-        run_benchmark("SipHash-2-4", siphash::<2, 4>),
-        run_benchmark("RawSipHash-1-3", raw_siphash::<1, 3>),
-        run_benchmark("RawSipHash-2-4", raw_siphash::<2, 4>),
-        run_benchmark("std", std_hasher),
+    use lccc_siphash::siphash::backend::{Generic, Sse};
+
+    // If an argument is given, only the benchmarks with a label that contains it are run
+    let filter = std::env::args().nth(1).unwrap_or_default();
+
+    let benches: &[Benchmark] = &[
+        ("Baseline", 0, baseline),
+        ("Sipround", 0, sipround),
+        ("SipHash-1-3", 0, siphash::<1, 3>),
+        ("SipHash-2-4", 0, siphash::<2, 4>),
+        ("RawSipHash-1-3", 0, raw_siphash::<1, 3>),
+        ("RawSipHash-2-4", 0, raw_siphash::<2, 4>),
+        ("std", 0, std_hasher),
+        // The scalar and SSE2 round implementations, on an 8 byte and a 4 KiB message
+        ("Generic SipHash-1-3 8B", 8, message::<1, 3, Generic, 8>),
+        (
+            "Generic SipHash-1-3 4KiB",
+            4096,
+            message::<1, 3, Generic, 4096>,
+        ),
+        ("Generic SipHash-2-4 8B", 8, message::<2, 4, Generic, 8>),
+        (
+            "Generic SipHash-2-4 4KiB",
+            4096,
+            message::<2, 4, Generic, 4096>,
+        ),
+        ("Sse SipHash-1-3 8B", 8, message::<1, 3, Sse, 8>),
+        ("Sse SipHash-1-3 4KiB", 4096, message::<1, 3, Sse, 4096>),
+        ("Sse SipHash-2-4 8B", 8, message::<2, 4, Sse, 8>),
+        ("Sse SipHash-2-4 4KiB", 4096, message::<2, 4, Sse, 4096>),
     ];
 
-    for bench in benches {
+    let stats: Vec<_> = benches
+        .iter()
+        .filter(|(label, ..)| label.contains(&*filter))
+        .map(|&(label, bytes, bench_fn)| run_benchmark(label, bytes, bench_fn))
+        .collect();
+
+    for bench in stats {
         println!("{}", bench)
     }
 }
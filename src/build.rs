@@ -2,11 +2,12 @@
 
 use core::hash::BuildHasher;
 
-use crate::{BuildSipHasher, SipHasher};
+use crate::{BuildSipHasher, SipHasher, SipKey};
 
 /// [`RandomState`] is a [`BuildHasher`] that yields the [`SipHasher`] type. Rather than being constructed from fixed keys,
 /// it has a single constructor [`RandomState::new`], which generates random keys.
 /// The resulting [`RandomState`] will then produce equal [`SipHasher`] instances for each [`BuildHasher::build_hasher`] call, but different [`RandomState`] instances will have different values.
+/// Like [`BuildSipHasher`], it stores the keyed initial state rather than the keys.
 ///
 /// `C` and `D` are the parameters of SipHash-*C*-*D* for the returned [`SipHasher`] instance
 #[derive(Clone, Debug)]
@@ -24,16 +25,41 @@ impl<const C: usize, const D: usize> RandomState<C, D> {
     /// The precise distribution of values is not specified and depends on the quality of the underlying random number generator, however on most platforms,
     ///  it should take, on average, 2^64 calls to this function to have a 50% chance of any two instances using identical keys.
     pub fn new() -> Self {
-        let mut bytes = [[0u8; 8]; 2];
+        Self::from_key(SipKey::from_system_rng())
+    }
+
+    /// Constructs a [`RandomState`] that uses `key`, rather than a newly generated key.
+    ///
+    /// This is intended for a key that was itself generated randomly (for example by [`SipKey::from_system_rng`]), and must be shared or reused,
+    ///  such as a key that is sent to another process so that it produces the same hashes.
+    pub const fn from_key(key: SipKey) -> Self {
+        Self(BuildSipHasher::from_key(key))
+    }
+}
 
-        getrandom::fill(bytes.as_flattened_mut()).unwrap();
+impl<const C: usize, const D: usize> RandomState<C, D> {
+    /// Hashes a single `u32`, as if by [`BuildSipHasher::hash_u32`]
+    #[inline]
+    pub fn hash_u32(&self, i: u32) -> u64 {
+        self.0.hash_u32(i)
+    }
 
-        let [k0, k1] = bytes;
+    /// Hashes a single `u64`, as if by [`BuildSipHasher::hash_u64`]
+    #[inline]
+    pub fn hash_u64(&self, i: u64) -> u64 {
+        self.0.hash_u64(i)
+    }
+
+    /// Hashes a single `usize`, as if by [`BuildSipHasher::hash_usize`]
+    #[inline]
+    pub fn hash_usize(&self, i: usize) -> u64 {
+        self.0.hash_usize(i)
+    }
 
-        Self(BuildSipHasher::new_with_keys(
-            u64::from_ne_bytes(k0),
-            u64::from_ne_bytes(k1),
-        ))
+    /// Returns `hasher` to the keyed initial state of this [`RandomState`], as if by [`BuildSipHasher::reset`]
+    #[inline]
+    pub fn reset(&self, hasher: &mut SipHasher<C, D>) {
+        self.0.reset(hasher)
     }
 }
 
@@ -0,0 +1,592 @@
+//! Module providing implementations of HalfSipHash primitives and hashers
+//!
+//! HalfSipHash is the variant of SipHash that operates on a state of four 32-bit words, using a 64-bit key and producing a 32-bit or 64-bit result.
+//! It is intended for targets where 64-bit arithmetic is expensive. It is defined by the SipHash reference implementation at <https://github.com/veorq/SipHash>,
+//!  and uses generic parameters for *C* and *D* in the same manner as [`crate::siphash`][mod@crate::siphash].
+use core::hash::Hasher;
+
+const HALFSIPHASH_MAG1: u32 = 0x6c796765;
+const HALFSIPHASH_MAG2: u32 = 0x74656462;
+
+/// Raw state implementation of HalfSipHash.
+/// This provides primitive operations like the HalfSipHash round function, in the same manner as [`SipHashState`][crate::SipHashState].
+///
+/// Logically, the [`HalfSipHashState`] is the state array `[s0, s1, s2, s3]`.
+/// Use [`HalfSipHashState::inspect_state`] and [`HalfSipHashState::from_state`] as primitives to access the underlying state array.
+#[derive(Copy, Clone, Debug)]
+pub struct HalfSipHashState(u32, u32, u32, u32);
+
+impl HalfSipHashState {
+    /// Constructs a new [`HalfSipHashState`] from the specified keys.
+    /// Per the specification, this initializes the state to `[k0, k1, k0 ^ 0x6c796765, k1 ^ 0x74656462]`
+    #[inline]
+    pub const fn from_keys(k0: u32, k1: u32) -> Self {
+        Self(k0, k1, k0 ^ HALFSIPHASH_MAG1, k1 ^ HALFSIPHASH_MAG2)
+    }
+
+    /// Constructs a new [`HalfSipHashState`] for 64-bit output from the specified keys.
+    /// This is the same as [`HalfSipHashState::from_keys`], except that `0xee` is additionally xored into s1, as the specification requires for 64-bit output.
+    #[inline]
+    pub const fn from_keys64(k0: u32, k1: u32) -> Self {
+        Self(k0, k1 ^ 0xee, k0 ^ HALFSIPHASH_MAG1, k1 ^ HALFSIPHASH_MAG2)
+    }
+
+    /// Constructs a new [`HalfSipHashState`] from the state array.
+    #[inline]
+    pub const fn from_state([v0, v1, v2, v3]: [u32; 4]) -> Self {
+        Self(v0, v1, v2, v3)
+    }
+
+    /// Returns the current state array of the [`HalfSipHashState`]. This function is intended for serialization and for debugging only (not to modify the state array)
+    #[inline]
+    pub const fn inspect_state(&self) -> [u32; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    /// Performs the update operation to injest `word` before applying the update rounds to the state array.
+    /// This mutates the state by xoring `word` into s3.
+    #[inline]
+    pub fn update_before_rounds(&mut self, word: u32) {
+        self.3 ^= word;
+    }
+
+    /// Performs the update operation to injest `word` after applying the update rounds to the state array.
+    /// This mutates the state by xoring `word` into s0.
+    #[inline]
+    pub fn update_after_rounds(&mut self, word: u32) {
+        self.0 ^= word;
+    }
+
+    /// Performs the update operation before the finalization rounds.
+    /// This mutates the state by xoring `0xff` into s2.
+    #[inline]
+    pub fn update_before_final(&mut self) {
+        self.2 ^= 0xff;
+    }
+
+    /// Performs the update operation before the first set of finalization rounds of 64-bit HalfSipHash.
+    /// This mutates the state by xoring `0xee` into s2.
+    #[inline]
+    pub fn update_before_final64(&mut self) {
+        self.2 ^= 0xee;
+    }
+
+    /// Performs the update operation before the second set of finalization rounds of 64-bit HalfSipHash, which produces the high word of the result.
+    /// This mutates the state by xoring `0xdd` into s1.
+    #[inline]
+    pub fn update_before_final64_high(&mut self) {
+        self.1 ^= 0xdd;
+    }
+
+    /// Consumes the state and produces the final value.
+    ///
+    /// Unlike SipHash, HalfSipHash only xors together s1 and s3 to produce the result.
+    /// Note that this does not apply the finalization rounds, and you must perform these rounds manually (or call [`HalfSipHashState::update_and_final`]).
+    #[inline]
+    pub fn finish(self) -> u32 {
+        self.1 ^ self.3
+    }
+
+    /// Performs a single HalfSipHash round operation on the state array.
+    #[inline]
+    pub fn round(&mut self) {
+        let Self(mut v0, mut v1, mut v2, mut v3) = *self;
+        v0 = v0.wrapping_add(v1);
+        v1 = v1.rotate_left(5) ^ v0;
+        v0 = v0.rotate_left(16);
+        v2 = v2.wrapping_add(v3);
+        v3 = v3.rotate_left(8) ^ v2;
+        v0 = v0.wrapping_add(v3);
+        v3 = v3.rotate_left(7) ^ v0;
+        v2 = v2.wrapping_add(v1);
+        v1 = v1.rotate_left(13) ^ v2;
+        v2 = v2.rotate_left(16);
+        *self = Self(v0, v1, v2, v3);
+    }
+
+    /// Injests `val` and then performs `R` HalfSipHash rounds.
+    /// Convience wrapper for calling [`Self::update_before_rounds`], then R calls to [`Self::round`], then a call to [`Self::update_after_rounds`]
+    pub fn update_and_round<const R: usize>(&mut self, val: u32) {
+        self.update_before_rounds(val);
+        for _ in 0..R {
+            self.round();
+        }
+        self.update_after_rounds(val);
+    }
+
+    /// Consumes the state, and performs the full finalization step with R finalization rounds.
+    /// Convience wrapper arround consuming the value (copying if necessary),
+    ///  calling [`Self::update_before_final`], then R calls to [`Self::round`], then a call to [`Self::finish`]
+    pub fn update_and_final<const R: usize>(mut self) -> u32 {
+        self.update_before_final();
+        for _ in 0..R {
+            self.round();
+        }
+        self.finish()
+    }
+
+    /// Consumes the state, and performs the full 64-bit HalfSipHash finalization step with R finalization rounds for each half of the result.
+    /// Convience wrapper arround calling [`Self::update_before_final64`], then R calls to [`Self::round`], then a call to [`Self::finish`] to produce the low word,
+    ///  followed by [`Self::update_before_final64_high`], then R calls to [`Self::round`], then a call to [`Self::finish`] to produce the high word.
+    pub fn update_and_final64<const R: usize>(mut self) -> u64 {
+        self.update_before_final64();
+        for _ in 0..R {
+            self.round();
+        }
+        let l = self.finish() as u64;
+        self.update_before_final64_high();
+        for _ in 0..R {
+            self.round();
+        }
+        let h = self.finish() as u64;
+
+        l | (h << 32)
+    }
+}
+
+/// A raw [`Hasher`] that directly wraps a [`HalfSipHashState`]. This is the HalfSipHash counterpart of [`RawSipHasher`][crate::RawSipHasher].
+///
+/// [`RawHalfSipHasher`] does not buffer bytes internally, and instead pads out values and byte arrays to 4 bytes, and splits larger values into 4 byte words.
+/// This produces different results from [`HalfSipHasher`] when several smaller values are hashed.
+///
+/// `C` and `D` are the parameters of HalfSipHash-*C*-*D*.
+#[derive(Copy, Clone, Debug)]
+pub struct RawHalfSipHasher<const C: usize, const D: usize>(HalfSipHashState);
+
+impl<const C: usize, const D: usize> RawHalfSipHasher<C, D> {
+    /// Constructs a new [`RawHalfSipHasher`]. This constructs the internal state as if by [`HalfSipHashState::from_keys`]
+    pub const fn from_keys(k0: u32, k1: u32) -> Self {
+        Self(HalfSipHashState::from_keys(k0, k1))
+    }
+
+    /// Constructs a new [`RawHalfSipHasher`] for use with [`RawHalfSipHasher::finish64`]. This constructs the internal state as if by [`HalfSipHashState::from_keys64`]
+    pub const fn from_keys64(k0: u32, k1: u32) -> Self {
+        Self(HalfSipHashState::from_keys64(k0, k1))
+    }
+
+    /// Constructs a [`RawHalfSipHasher`] that wraps a given internal state.
+    #[cfg(feature = "inspect-raw")]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
+    pub const fn from_state(state: HalfSipHashState) -> Self {
+        Self(state)
+    }
+
+    /// Obtains the inner state for the purposes of debugging and serialization.
+    #[cfg(feature = "inspect-raw")]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
+    pub const fn state(&self) -> &HalfSipHashState {
+        &self.0
+    }
+
+    /// Updates the state by writing a word, and performing `C` rounds.
+    pub fn update(&mut self, word: u32) {
+        self.0.update_and_round::<C>(word)
+    }
+
+    /// Finishes the Hash by performing the finalization steps of a fresh copy of the state, before producing the 32-bit final value of the hash
+    pub fn finish32(&self) -> u32 {
+        self.0.update_and_final::<D>()
+    }
+
+    /// Finishes the Hash by performing the 64-bit HalfSipHash finalization steps of a fresh copy of the state, before producing a 64-bit final value of the hash.
+    ///
+    /// The hasher should be constructed with [`RawHalfSipHasher::from_keys64`] when using this function.
+    pub fn finish64(&self) -> u64 {
+        self.0.update_and_final64::<D>()
+    }
+
+    /// Updates the hash using each 4 byte chunk of `bytes`, padding the remainder (if any) with 0 bytes.
+    pub fn update_from_bytes(&mut self, bytes: &[u8]) {
+        let (chunks, rem) = bytes.as_chunks::<4>();
+        for &chunk in chunks {
+            self.update(u32::from_le_bytes(chunk));
+        }
+
+        let mut v = [0x00; 4];
+        v[..rem.len()].copy_from_slice(rem);
+        if !rem.is_empty() {
+            self.update(u32::from_le_bytes(v))
+        }
+    }
+
+    /// Updates the hash using each 4 byte chunk of `st`, padding the remainder with a minimum of 1 0xFF byte.
+    ///
+    /// When the `nightly-prefixfree_extras` feature is enabled, [`Hasher::write_str`] has the same effect as this function
+    pub fn update_from_string(&mut self, st: &str) {
+        let bytes = st.as_bytes();
+        let (chunks, rem) = bytes.as_chunks::<4>();
+        for &chunk in chunks {
+            self.update(u32::from_le_bytes(chunk));
+        }
+
+        let mut v = [0xFF; 4];
+        v[..rem.len()].copy_from_slice(rem);
+
+        self.update(u32::from_le_bytes(v))
+    }
+}
+
+impl<const C: usize, const D: usize> Hasher for RawHalfSipHasher<C, D> {
+    /// Returns the 32-bit result of [`RawHalfSipHasher::finish32`], zero extended
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update_from_bytes(bytes);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.update(i);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.update(i as u32);
+        self.update((i >> 32) as u32);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.write_u32(i as u32)
+    }
+
+    #[cfg(feature = "nightly-prefixfree_extras")]
+    fn write_str(&mut self, s: &str) {
+        self.update_from_string(s);
+    }
+}
+
+/// [`HalfSipHasher`] is a complete implementation of HalfSipHash with 32-bit output, including buffering of written bytes.
+///
+/// The result of [`HalfSipHasher::finish32`] is the reference 4 byte output read as a little-endian integer. [`Hasher::finish`] returns that value zero extended.
+/// As with [`SipHasher`][crate::SipHasher], integers are written as their little-endian bytes, and `usize` and `isize` are widened to 64 bits, so the same value is produced on every target.
+///
+/// A hasher built by a [`BuildHalfSipHasher`][crate::BuildHalfSipHasher] can be returned to its keyed initial state with [`BuildHalfSipHasher::reset`][crate::BuildHalfSipHasher::reset].
+///
+/// `C` and `D` are the parameters of HalfSipHash-*C*-*D*.
+#[derive(Copy, Clone, Debug)]
+pub struct HalfSipHasher<const C: usize, const D: usize> {
+    state: HalfSipHashState,
+    tail: u32,
+    ntail: usize,
+    bytes: usize,
+}
+
+impl<const C: usize, const D: usize> HalfSipHasher<C, D> {
+    /// Constructs a new [`HalfSipHasher`] from a default state using keys k0 and k1
+    pub const fn new_with_keys(k0: u32, k1: u32) -> Self {
+        Self::from_initial_state(HalfSipHashState::from_keys(k0, k1))
+    }
+
+    const fn from_initial_state(state: HalfSipHashState) -> Self {
+        Self {
+            state,
+            tail: 0,
+            ntail: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Convience function that updates the state with the specified word
+    pub fn update(&mut self, word: u32) {
+        self.state.update_and_round::<C>(word)
+    }
+
+    /// Finishes the Hash by performing the finalization steps of a fresh copy of the state, before producing the 32-bit final value of the hash
+    pub fn finish32(&self) -> u32 {
+        self.final_state().update_and_final::<D>()
+    }
+
+    /// Obtains the underlying raw [`HalfSipHashState`]
+    #[cfg(feature = "inspect-raw")]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
+    pub const fn state(&self) -> &HalfSipHashState {
+        &self.state
+    }
+
+    /// Constructs a new [`HalfSipHasher`] from the specified raw state. Note that the current hash state outside of the [`HalfSipHashState`] is not preserved,
+    ///   and any words that have not yet been finished are discarded in a roundtrip through this function and [`HalfSipHasher::state`].
+    #[cfg(feature = "inspect-raw")]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
+    pub const fn from_state(state: HalfSipHashState) -> Self {
+        Self::from_initial_state(state)
+    }
+
+    /// Produces a copy of the state that has injested the final word (the remaining tail bytes and the message length), ready for the finalization rounds.
+    fn final_state(&self) -> HalfSipHashState {
+        let mut state = self.state;
+        state.update_and_round::<C>(self.tail | (((self.bytes as u32) & 0xFF) << 24));
+        state
+    }
+
+    /// Writes the little-endian bytes of an integer, with the same result as passing `bytes` to [`Hasher::write`].
+    /// `N` must be at most 4, so the bytes are zero extended to a word by a fixed size copy, rather than a call to `memcpy`.
+    #[inline(always)]
+    fn write_le<const N: usize>(&mut self, bytes: [u8; N]) {
+        let mut word = [0u8; 4];
+        word[..N].copy_from_slice(&bytes);
+        self.write_partial_word(u32::from_le_bytes(word), N);
+    }
+
+    /// Writes the `len` low bytes of `word` (read as little-endian), where the remaining bytes of `word` are 0.
+    /// `len` is between 1 and 4.
+    #[inline(always)]
+    fn write_partial_word(&mut self, word: u32, len: usize) {
+        self.bytes = self.bytes.wrapping_add(len);
+        let shift = self.ntail << 3;
+        let merged = self.tail | (word << shift);
+        let ntail = self.ntail + len;
+        if ntail < 4 {
+            self.tail = merged;
+            self.ntail = ntail;
+            return;
+        }
+
+        self.update(merged);
+        // The bytes of `word` that did not fit in the pending word. This is `word >> (32 - shift)`, split so that it is 0 rather than overflowing when `shift` is 0
+        self.tail = (word >> 1) >> (31 - shift);
+        self.ntail = ntail - 4;
+    }
+}
+
+/// Reads up to 4 bytes as a little-endian word, padding the remainder with 0 bytes
+#[inline]
+fn load_partial(bytes: &[u8]) -> u32 {
+    let mut word = [0u8; 4];
+    word[..bytes.len()].copy_from_slice(bytes);
+    u32::from_le_bytes(word)
+}
+
+impl<const C: usize, const D: usize> Hasher for HalfSipHasher<C, D> {
+    fn write(&mut self, mut s: &[u8]) {
+        self.bytes = self.bytes.wrapping_add(s.len());
+        if self.ntail > 0 {
+            let required = s.len().min(4 - self.ntail);
+            let (l, r) = s.split_at(required);
+
+            self.tail |= load_partial(l) << (self.ntail << 3);
+            self.ntail += required;
+            s = r;
+
+            if self.ntail < 4 {
+                return;
+            }
+
+            self.update(self.tail);
+            self.tail = 0;
+            self.ntail = 0;
+        }
+
+        let (chunks, remainder) = s.as_chunks::<4>();
+
+        for &chunk in chunks {
+            self.update(u32::from_le_bytes(chunk));
+        }
+
+        self.tail = load_partial(remainder);
+        self.ntail = remainder.len();
+    }
+
+    /// Returns the 32-bit result of [`HalfSipHasher::finish32`], zero extended
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    // The integer writes have the same result as writing their little-endian bytes, but merge the integer into the pending word directly
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write_le(i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write_le(i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write_le(i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write_u32(i as u32);
+        self.write_u32((i >> 32) as u32);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+/// [`HalfSipHasher64`] is a complete implementation of HalfSipHash with 64-bit output.
+///
+/// This buffers written bytes in the same way as [`HalfSipHasher`], but uses the keyed initial state and finalization steps the specification defines for 64-bit output.
+///
+/// `C` and `D` are the parameters of HalfSipHash-*C*-*D*.
+#[derive(Copy, Clone, Debug)]
+pub struct HalfSipHasher64<const C: usize, const D: usize>(HalfSipHasher<C, D>);
+
+impl<const C: usize, const D: usize> HalfSipHasher64<C, D> {
+    /// Constructs a new [`HalfSipHasher64`] from a default state using keys k0 and k1
+    pub const fn new_with_keys(k0: u32, k1: u32) -> Self {
+        Self(HalfSipHasher::from_initial_state(
+            HalfSipHashState::from_keys64(k0, k1),
+        ))
+    }
+
+    /// Finishes the Hash by performing the 64-bit finalization steps of a fresh copy of the state, before producing the 64-bit final value of the hash.
+    ///
+    /// The result is the reference 8 byte output read as a little-endian integer (the low word is produced first).
+    pub fn finish64(&self) -> u64 {
+        self.0.final_state().update_and_final64::<D>()
+    }
+}
+
+impl<const C: usize, const D: usize> Hasher for HalfSipHasher64<C, D> {
+    fn write(&mut self, s: &[u8]) {
+        self.0.write(s);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish64()
+    }
+
+    // The integer writes use the encoding of [`HalfSipHasher`], rather than the native-endian default
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.0.write_u8(i);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.0.write_u16(i);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.0.write_u32(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.0.write_u64(i);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.0.write_u128(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.0.write_usize(i);
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.0.write_i8(i);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.0.write_i16(i);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.0.write_i32(i);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.0.write_i64(i);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.0.write_i128(i);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.0.write_isize(i);
+    }
+}
@@ -0,0 +1,244 @@
+//! Module providing [`std::io`] support: [`Write`] impls for the hashers, hashing of readers, and adapters that hash data as it is read or written.
+
+use core::hash::Hasher;
+use std::io::{self, IoSlice, IoSliceMut, Read, Write};
+
+use crate::{RawSipHasher, SipBackend, SipHasher, SipHasher128, SipKey};
+
+/// Writes to a [`SipHasher`] are hashed as if by [`Hasher::write`].
+///
+/// Because [`SipHasher`] buffers partial words, the result does not depend on how the data is split between writes, so this can be used with [`io::copy`].
+impl<const C: usize, const D: usize, B: SipBackend> Write for SipHasher<C, D, B> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Hasher::write(self, buf);
+        Ok(buf.len())
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut len = 0;
+        for buf in bufs {
+            Hasher::write(self, buf);
+            len += buf.len();
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        Hasher::write(self, buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes to a [`SipHasher128`] are hashed as if by [`Hasher::write`], in the same way as for [`SipHasher`].
+impl<const C: usize, const D: usize> Write for SipHasher128<C, D> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Hasher::write(self, buf);
+        Ok(buf.len())
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut len = 0;
+        for buf in bufs {
+            Hasher::write(self, buf);
+            len += buf.len();
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        Hasher::write(self, buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Each write to a [`RawSipHasher`] is ingested as if by [`RawSipHasher::update_from_bytes`], and each slice passed to [`Write::write_vectored`] is ingested separately.
+///
+/// Unlike [`SipHasher`], [`RawSipHasher`] does not buffer partial words, so the result depends on how the data is split between writes.
+/// Use [`SipHasher`] with [`io::copy`], or other callers that choose their own buffer sizes.
+impl<const C: usize, const D: usize, B: SipBackend> Write for RawSipHasher<C, D, B> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update_from_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut len = 0;
+        for buf in bufs {
+            self.update_from_bytes(buf);
+            len += buf.len();
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Hashes everything read from `reader` (until it reports the end of the stream) with SipHash-`C`-`D` and `key`.
+///
+/// The result is the same as [`siphash`][crate::siphash::siphash] of all of the bytes read.
+pub fn hash_reader<const C: usize, const D: usize, R: Read>(
+    key: &SipKey,
+    mut reader: R,
+) -> io::Result<u64> {
+    let mut hasher = SipHasher::<C, D>::from_key(*key);
+    io::copy(&mut reader, &mut hasher)?;
+    Ok(hasher.finish())
+}
+
+/// Hashes everything read from `reader` (until it reports the end of the stream) with SipHash-128 and `key`.
+///
+/// The result is the same as [`siphash128`][crate::siphash::siphash128] of all of the bytes read.
+pub fn hash_reader128<const C: usize, const D: usize, R: Read>(
+    key: &SipKey,
+    mut reader: R,
+) -> io::Result<u128> {
+    let mut hasher = SipHasher128::<C, D>::from_key(*key);
+    io::copy(&mut reader, &mut hasher)?;
+    Ok(hasher.finish128())
+}
+
+/// Writes every byte in `bufs` up to `len` to `hasher`, stopping partway through a slice if needed
+fn write_prefix<'a, H: Hasher>(
+    hasher: &mut H,
+    bufs: impl IntoIterator<Item = &'a [u8]>,
+    mut len: usize,
+) {
+    for buf in bufs {
+        if len == 0 {
+            break;
+        }
+        let n = buf.len().min(len);
+        hasher.write(&buf[..n]);
+        len -= n;
+    }
+}
+
+/// [`HashingWriter`] is a [`Write`] adapter that writes to an inner writer, and hashes the data written.
+///
+/// Only the bytes that the inner writer accepts are hashed, so after a short write or an error, the hash covers exactly the data that was written.
+#[derive(Clone, Debug)]
+pub struct HashingWriter<W, H> {
+    inner: W,
+    hasher: H,
+}
+
+impl<W, H: Hasher> HashingWriter<W, H> {
+    /// Constructs a new [`HashingWriter`] that writes to `inner` and hashes with `hasher`
+    pub const fn new(inner: W, hasher: H) -> Self {
+        Self { inner, hasher }
+    }
+
+    /// Returns a reference to the inner writer
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer. Data written through this reference is not hashed.
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the hasher, for example to compute a 128-bit result with [`SipHasher128::finish128`]
+    pub const fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the hash of the data written so far
+    pub fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+
+    /// Returns the inner writer and the hasher
+    pub fn into_inner(self) -> (W, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<W: Write, H: Hasher> Write for HashingWriter<W, H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.write(&buf[..n]);
+        Ok(n)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let n = self.inner.write_vectored(bufs)?;
+        write_prefix(&mut self.hasher, bufs.iter().map(|buf| &**buf), n);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// [`HashingReader`] is a [`Read`] adapter that reads from an inner reader, and hashes the data read.
+#[derive(Clone, Debug)]
+pub struct HashingReader<R, H> {
+    inner: R,
+    hasher: H,
+}
+
+impl<R, H: Hasher> HashingReader<R, H> {
+    /// Constructs a new [`HashingReader`] that reads from `inner` and hashes with `hasher`
+    pub const fn new(inner: R, hasher: H) -> Self {
+        Self { inner, hasher }
+    }
+
+    /// Returns a reference to the inner reader
+    pub const fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader. Data read through this reference is not hashed.
+    pub const fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the hasher, for example to compute a 128-bit result with [`SipHasher128::finish128`]
+    pub const fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the hash of the data read so far
+    pub fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+
+    /// Returns the inner reader and the hasher
+    pub fn into_inner(self) -> (R, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<R: Read, H: Hasher> Read for HashingReader<R, H> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.write(&buf[..n]);
+        Ok(n)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let n = self.inner.read_vectored(bufs)?;
+        write_prefix(&mut self.hasher, bufs.iter().map(|buf| &**buf), n);
+        Ok(n)
+    }
+}
@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![deny(missing_docs)]
 #![cfg_attr(feature = "nightly-docs", feature(doc_cfg))]
 #![cfg_attr(
@@ -8,8 +8,12 @@
 
 //! lccc-siphash implements the SipHash algorithm with a generic number of update and finalize rounds.
 //!
+//! The [`halfsiphash`] module additionally implements HalfSipHash, the variant of SipHash with a 32-bit state, for targets where 64-bit arithmetic is expensive.
+//! The [`phf`] module provides [`SipPhfMap`], a static perfect hash map that is generated at compile time by [`sip_phf_map!`].
+//!
 //! The implementation is designed to be highly optimized, making use of low-level hardware instructions to generate the most efficient code possible.
 //! This is based on the static set of supported target features and the cpu. Because of this, compilation with -C target-cpu is recommended.
+//! The implementation can also be chosen explicitly, or replaced, through the [`SipBackend`] parameter of [`SipHasher`] and [`RawSipHasher`] (see [`siphash::backend`]).
 //!
 //! ## Features
 //!
@@ -18,7 +22,13 @@
 //! * `rng`: Adds the type [`rng::SiphashRng`], to generate random numbers using the siphash impl
 //! * `rand_core`: Adds the optional `rand_core` dependency and implements it for [`rng::SiphashRng`]
 //! * `serde`: Adds serde support for serializing and deserializing raw states.
-//! * `random-state`: Adds the type [`build::RandomState`] as a [`BuildHasher`] impl. This adds a dependency on the `getrandom` crate.
+//! * `random-state`: Adds the type [`build::RandomState`] as a [`BuildHasher`] impl, and [`SipKey::from_system_rng`] to generate random keys. This adds a dependency on the `getrandom` crate.
+//! * `runtime-dispatch`: On x86 targets, detects the CPU features once at runtime, and uses the best available round implementation (AVX2 or AVX-512) when hashing large inputs,
+//!   even if those features are not enabled at compile time. This enables the `std` feature.
+//! * `std`: Adds the [`io`] module, which implements [`std::io::Write`] for the hashers, and provides [`io::hash_reader`] and the [`io::HashingWriter`] and [`io::HashingReader`] adapters.
+//! * `digest`: Implements the RustCrypto `digest` traits (`Mac`, `KeyInit`, `Update`, `FixedOutput` and `Reset`) for [`SipMac`] and [`SipMac128`],
+//!   so that (for example) SipHash-2-4 and SipHash-2-4-128 can be used in the same way as the `hmac` and `cmac` crates. Keys are the 16 bytes of the reference key layout.
+//!   This adds a dependency on the `digest` crate.
 //! * `nightly-prefixfree_extras`: Implements [`Hasher::write_str`][core::hash::Hasher::write_str] in an optimized way. Note that this changes the results of hashes that involve `str` or `String`.
 //!
 //! ## [`RandomState`][build::RandomState] and wasm
@@ -30,6 +40,14 @@
 #[allow(unexpected_cfgs)]
 pub mod siphash;
 
+pub mod halfsiphash;
+
+pub mod phf;
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "std")))]
+pub mod io;
+
 #[cfg(any(doc, feature = "rng"))]
 pub mod rng;
 
@@ -37,21 +55,46 @@ use core::hash::BuildHasher;
 
 #[cfg(feature = "rand_core")]
 use rand_core::{Rng, TryRng};
+pub use siphash::MultiKeySipHasher;
 pub use siphash::RawSipHasher;
+pub use siphash::SipBackend;
 pub use siphash::SipHashState;
+pub use siphash::SipHashStateX;
+pub use siphash::SipHashStateX4;
+pub use siphash::SipHashStateX8;
 pub use siphash::SipHasher;
+pub use siphash::SipHasher128;
+pub use siphash::SipKey;
+pub use siphash::StdCompatSipHasher;
+pub use siphash::{siphash, siphash128};
+pub use siphash::{SipMac, SipMac128};
+
+pub use phf::SipPhfMap;
+
+pub use halfsiphash::HalfSipHashState;
+pub use halfsiphash::HalfSipHasher;
+pub use halfsiphash::HalfSipHasher64;
+pub use halfsiphash::RawHalfSipHasher;
 
 /// Default [`BuildHasher`] for [`SipHasher`]. `C` and `D` are the configuration parameters for SipHash-*C*-*D*, specifying the number of update rounds (C) and finalization rounds (D).
 #[derive(Clone, Debug)]
 pub struct BuildSipHasher<const C: usize, const D: usize> {
-    k0: u64,
-    k1: u64,
+    initial: SipHashState,
 }
 
 impl<const C: usize, const D: usize> BuildSipHasher<C, D> {
     /// Constructs a new [`BuildSipHasher`] with the specified set of keys. All [`BuildSipHasher`] instances constructed with the same keys will produce identical hashers.
+    ///
+    /// The keyed initial state is computed once here, and copied into each hasher.
     pub const fn new_with_keys(k0: u64, k1: u64) -> Self {
-        Self { k0, k1 }
+        Self {
+            initial: SipHashState::from_keys(k0, k1),
+        }
+    }
+
+    /// Constructs a new [`BuildSipHasher`] with the specified key, as if by [`BuildSipHasher::new_with_keys`].
+    pub const fn from_key(key: SipKey) -> Self {
+        Self::new_with_keys(key.k0(), key.k1())
     }
 
     /// Constructs a new [`BuildSipHasher`] with keys populated from the specified [`Rng`].
@@ -73,12 +116,125 @@ impl<const C: usize, const D: usize> BuildSipHasher<C, D> {
 
         Ok(Self::new_with_keys(k0, k1))
     }
+
+    /// Hashes each of `messages`, storing the result in the corresponding element of `out`.
+    ///
+    /// Each result is the same as writing the message to a hasher from [`BuildHasher::build_hasher`] with a single call to [`Hasher::write`][core::hash::Hasher::write], and then calling [`Hasher::finish`][core::hash::Hasher::finish].
+    /// Note that this differs from [`BuildHasher::hash_one`], which also hashes the length of a slice.
+    ///
+    /// With AVX2 or AVX-512 enabled, several messages are hashed at once using a [`SipHashStateX`] (4 lanes with AVX2, and 8 with AVX-512).
+    /// This is fastest when the messages in each group of 4 or 8 have similar lengths, because the group takes as long as its longest message.
+    /// On other targets, the messages are hashed one at a time.
+    ///
+    /// ## Panics
+    /// Panics if `messages` and `out` have different lengths
+    pub fn hash_batch(&self, messages: &[&[u8]], out: &mut [u64]) {
+        siphash::hash_batch::<C, D>(self.initial, messages, out)
+    }
+
+    /// Hashes a single `u32`, with the same result as [`BuildHasher::hash_one`].
+    ///
+    /// The integer and the message length are packed into a single final word, so this performs no buffering at all.
+    ///
+    /// [`HashMap`][std::collections::HashMap] and other users of [`BuildHasher::hash_one`] reach the same code for integer keys:
+    ///  the first integer written to a new [`SipHasher`] is not buffered, so [`Hasher::write_u32`][core::hash::Hasher::write_u32] ingests it in the same way.
+    /// This method is a shorthand for callers that know the type of the key.
+    #[inline]
+    pub fn hash_u32(&self, i: u32) -> u64 {
+        self.hash_le(i.to_le_bytes())
+    }
+
+    /// Hashes a single `u64`, with the same result as [`BuildHasher::hash_one`].
+    ///
+    /// This ingests the integer as a single word, followed by the final word, and performs no buffering at all.
+    /// Like [`BuildSipHasher::hash_u32`], this compiles to the same code as [`BuildHasher::hash_one`] for a `u64` key.
+    #[inline]
+    pub fn hash_u64(&self, i: u64) -> u64 {
+        self.hash_le(i.to_le_bytes())
+    }
+
+    /// Hashes a single `usize`, with the same result as [`BuildHasher::hash_one`], as if by [`BuildSipHasher::hash_u64`] (because [`SipHasher`] widens `usize` to 64 bits).
+    #[inline]
+    pub fn hash_usize(&self, i: usize) -> u64 {
+        self.hash_u64(i as u64)
+    }
+
+    /// Returns `hasher` to the keyed initial state of this [`BuildSipHasher`], discarding everything written to it.
+    ///
+    /// After this, `hasher` produces the same results as a new hasher from [`BuildHasher::build_hasher`], so it can be reused for a new message.
+    #[inline]
+    pub fn reset(&self, hasher: &mut SipHasher<C, D>) {
+        *hasher = SipHasher::from_initial_state(self.initial);
+    }
+
+    /// Hashes a message consisting only of `bytes`, where `N` is at most 8.
+    #[inline(always)]
+    fn hash_le<const N: usize>(&self, bytes: [u8; N]) -> u64 {
+        let mut word = [0u8; 8];
+        word[..N].copy_from_slice(&bytes);
+        let word = u64::from_le_bytes(word);
+
+        let mut state = self.initial;
+        let last = if N == 8 {
+            state.update_and_round::<C>(word);
+            0
+        } else {
+            word
+        };
+        state.update_and_round::<C>(last | ((N as u64) << 56));
+        state.update_and_final::<D>()
+    }
 }
 
 impl<const C: usize, const D: usize> BuildHasher for BuildSipHasher<C, D> {
     type Hasher = SipHasher<C, D>;
     fn build_hasher(&self) -> Self::Hasher {
-        SipHasher::new_with_keys(self.k0, self.k1)
+        SipHasher::from_initial_state(self.initial)
+    }
+}
+
+/// Default [`BuildHasher`] for [`HalfSipHasher`]. `C` and `D` are the configuration parameters for HalfSipHash-*C*-*D*, specifying the number of update rounds (C) and finalization rounds (D).
+#[derive(Clone, Debug)]
+pub struct BuildHalfSipHasher<const C: usize, const D: usize> {
+    k0: u32,
+    k1: u32,
+}
+
+impl<const C: usize, const D: usize> BuildHalfSipHasher<C, D> {
+    /// Constructs a new [`BuildHalfSipHasher`] with the specified set of keys. All [`BuildHalfSipHasher`] instances constructed with the same keys will produce identical hashers.
+    pub const fn new_with_keys(k0: u32, k1: u32) -> Self {
+        Self { k0, k1 }
+    }
+
+    /// Constructs a new [`BuildHalfSipHasher`] with keys populated from the specified [`Rng`].
+    #[cfg(feature = "rand_core")]
+    pub fn from_rng<R: Rng>(r: &mut R) -> Self {
+        let k0 = r.next_u32();
+        let k1 = r.next_u32();
+
+        Self::new_with_keys(k0, k1)
+    }
+
+    /// Constructs a new [`BuildHalfSipHasher`] with keys populated from the specified [`TryRng`], failing if an error occurs
+    #[cfg(feature = "rand_core")]
+    pub fn try_from_rng<R: TryRng>(r: &mut R) -> Result<Self, R::Error> {
+        let k0 = r.try_next_u32()?;
+        let k1 = r.try_next_u32()?;
+
+        Ok(Self::new_with_keys(k0, k1))
+    }
+
+    /// Returns `hasher` to the keyed initial state of this [`BuildHalfSipHasher`], discarding everything written to it, as [`BuildSipHasher::reset`] does for [`SipHasher`].
+    #[inline]
+    pub fn reset(&self, hasher: &mut HalfSipHasher<C, D>) {
+        *hasher = self.build_hasher();
+    }
+}
+
+impl<const C: usize, const D: usize> BuildHasher for BuildHalfSipHasher<C, D> {
+    type Hasher = HalfSipHasher<C, D>;
+    fn build_hasher(&self) -> Self::Hasher {
+        HalfSipHasher::new_with_keys(self.k0, self.k1)
     }
 }
 
@@ -87,8 +243,19 @@ pub mod build;
 
 #[cfg(test)]
 mod test {
-    use crate::SipHasher;
-    use core::hash::Hasher;
+    use crate::siphash::{self, backend};
+    use crate::{
+        phf, HalfSipHasher, HalfSipHasher64, MultiKeySipHasher, RawSipHasher, SipBackend,
+        SipHashState, SipHasher, SipHasher128, SipKey, SipMac, SipMac128, SipPhfMap,
+        StdCompatSipHasher,
+    };
+    use core::hash::{BuildHasher, Hash, Hasher};
+
+    pub(crate) mod reference;
+    mod vectors;
+    use vectors::*;
+
+    const REFERENCE_KEY: SipKey = SipKey::new(REFERENCE_K0, REFERENCE_K1);
 
     pub struct TestVector {
         k0: u64,
@@ -116,4 +283,1052 @@ mod test {
             assert_eq!(vec.expected, got, "{:#016x}!={:#016x}", vec.expected, got);
         }
     }
+
+    /// Checks each reference vector against the result of writing the message in pieces produced by `split`
+    fn check_vectors<H: Hasher, const N: usize>(
+        vectors: &[[u8; N]; 64],
+        new: impl Fn() -> H,
+        finish: impl Fn(&H) -> [u8; N],
+        split: impl Fn(&mut H, &[u8]),
+    ) {
+        for (len, expected) in vectors.iter().enumerate() {
+            let mut hasher = new();
+            split(&mut hasher, &REFERENCE_MESSAGE[..len]);
+            assert_eq!(*expected, finish(&hasher), "length {len}");
+        }
+    }
+
+    /// Runs [`check_vectors`] with a single write, every two-way split of the message, and byte-wise writes
+    fn check_vectors_streaming<H: Hasher, const N: usize>(
+        vectors: &[[u8; N]; 64],
+        new: impl Fn() -> H,
+        finish: impl Fn(&H) -> [u8; N],
+    ) {
+        check_vectors(vectors, &new, &finish, |hasher, msg| hasher.write(msg));
+        for split in 0..64 {
+            check_vectors(vectors, &new, &finish, |hasher, msg| {
+                let (l, r) = msg.split_at(split.min(msg.len()));
+                hasher.write(l);
+                hasher.write(r);
+            });
+        }
+        check_vectors(vectors, &new, &finish, |hasher, msg| {
+            for b in msg {
+                hasher.write(core::slice::from_ref(b));
+            }
+        });
+    }
+
+    fn check_siphash<const C: usize, const D: usize>(vectors: &[[u8; 8]; 64]) {
+        check_vectors(
+            vectors,
+            || SipHasher::<C, D>::new_with_keys(REFERENCE_K0, REFERENCE_K1),
+            |hasher| hasher.finish().to_le_bytes(),
+            |hasher, msg| hasher.write(msg),
+        );
+        for (len, expected) in vectors.iter().enumerate() {
+            let got =
+                siphash::const_hash::<C, D>(REFERENCE_K0, REFERENCE_K1, &REFERENCE_MESSAGE[..len]);
+            assert_eq!(*expected, got.to_le_bytes(), "const_hash, length {len}");
+            let got = crate::siphash::<C, D>(&REFERENCE_KEY, &REFERENCE_MESSAGE[..len]);
+            assert_eq!(*expected, got.to_le_bytes(), "siphash, length {len}");
+        }
+    }
+
+    fn check_siphash_streaming<const C: usize, const D: usize>(vectors: &[[u8; 8]; 64]) {
+        check_vectors_streaming(
+            vectors,
+            || SipHasher::<C, D>::new_with_keys(REFERENCE_K0, REFERENCE_K1),
+            |hasher| hasher.finish().to_le_bytes(),
+        );
+    }
+
+    fn check_siphash128<const C: usize, const D: usize>(vectors: &[[u8; 16]; 64]) {
+        check_vectors(
+            vectors,
+            || SipHasher128::<C, D>::new_with_keys(REFERENCE_K0, REFERENCE_K1),
+            |hasher| hasher.finish128().to_le_bytes(),
+            |hasher, msg| hasher.write(msg),
+        );
+        for (len, expected) in vectors.iter().enumerate() {
+            let got = siphash::const_hash128::<C, D>(
+                REFERENCE_K0,
+                REFERENCE_K1,
+                &REFERENCE_MESSAGE[..len],
+            );
+            assert_eq!(*expected, got.to_le_bytes(), "const_hash128, length {len}");
+            let got = crate::siphash128::<C, D>(&REFERENCE_KEY, &REFERENCE_MESSAGE[..len]);
+            assert_eq!(*expected, got.to_le_bytes(), "siphash128, length {len}");
+        }
+    }
+
+    #[test]
+    pub fn siphash_const_eval_tests() {
+        // Evaluated at compile time
+        const HASHES: [[u8; 8]; 64] = {
+            let mut hashes = [[0u8; 8]; 64];
+            let mut len = 0;
+            while len < 64 {
+                let (msg, _) = REFERENCE_MESSAGE.split_at(len);
+                hashes[len] =
+                    siphash::const_hash::<2, 4>(REFERENCE_K0, REFERENCE_K1, msg).to_le_bytes();
+                len += 1;
+            }
+            hashes
+        };
+        const HASH128: u128 = {
+            let (msg, _) = REFERENCE_MESSAGE.split_at(63);
+            siphash::const_hash128::<2, 4>(REFERENCE_K0, REFERENCE_K1, msg)
+        };
+
+        assert_eq!(SIPHASH_2_4_64, HASHES);
+        assert_eq!(SIPHASH_2_4_128[63], HASH128.to_le_bytes());
+    }
+
+    #[test]
+    pub fn siphash_key_tests() {
+        let bytes: [u8; 16] = core::array::from_fn(|i| i as u8);
+        assert_eq!(SipKey::from_bytes(bytes), REFERENCE_KEY);
+        assert_eq!(REFERENCE_KEY.to_bytes(), bytes);
+        assert_eq!(<[u8; 16]>::from(SipKey::from(bytes)), bytes);
+
+        const HEX: &str = "000102030405060708090a0b0c0d0e0f";
+        const PARSED: SipKey = match SipKey::from_hex(HEX) {
+            Ok(key) => key,
+            Err(_) => panic!(),
+        };
+        assert_eq!(PARSED, REFERENCE_KEY);
+        assert_eq!(std::format!("{REFERENCE_KEY}"), HEX);
+        assert_eq!(std::format!("{REFERENCE_KEY:X}"), HEX.to_ascii_uppercase());
+        assert_eq!(HEX.to_ascii_uppercase().parse(), Ok(REFERENCE_KEY));
+
+        let mut rng = TestRng(0xbb67ae8584caa73b);
+        for _ in 0..64 {
+            let key = SipKey::new(rng.next(), rng.next());
+            assert_eq!(std::format!("{key:x}").parse(), Ok(key));
+            assert_eq!(SipKey::from_bytes(key.to_bytes()), key);
+        }
+
+        assert_eq!(
+            "0001".parse::<SipKey>(),
+            Err(siphash::ParseSipKeyError::InvalidLength(4))
+        );
+        assert_eq!(
+            "000102030405060708090a0b0c0d0e0g".parse::<SipKey>(),
+            Err(siphash::ParseSipKeyError::InvalidDigit(31))
+        );
+        assert_eq!(
+            "+00102030405060708090a0b0c0d0e0f".parse::<SipKey>(),
+            Err(siphash::ParseSipKeyError::InvalidDigit(0))
+        );
+
+        let build = crate::BuildSipHasher::<2, 4>::from_key(REFERENCE_KEY);
+        assert_eq!(
+            build.hash_one(&REFERENCE_MESSAGE[..]),
+            crate::BuildSipHasher::<2, 4>::new_with_keys(REFERENCE_K0, REFERENCE_K1)
+                .hash_one(&REFERENCE_MESSAGE[..])
+        );
+    }
+
+    #[test]
+    pub fn siphash_mac_tests() {
+        let mut rng = TestRng(0x3c6ef372fe94f82b);
+        for len in 0..64 {
+            let msg = &REFERENCE_MESSAGE[..len];
+            let (head, tail) = msg.split_at(rng.below(len + 1));
+
+            let mut mac = SipMac::<2, 4>::new(&REFERENCE_KEY);
+            mac.update(head);
+            mac.update(tail);
+            assert_eq!(mac.clone().finalize(), SIPHASH_2_4_64[len], "length {len}");
+            assert_eq!(mac.clone().verify(&SIPHASH_2_4_64[len]), Ok(()));
+            for bit in 0..64 {
+                let mut tag = SIPHASH_2_4_64[len];
+                tag[bit / 8] ^= 1 << (bit % 8);
+                assert_eq!(mac.clone().verify(&tag), Err(siphash::MacError));
+            }
+
+            let mut mac = SipMac128::<2, 4>::new(&REFERENCE_KEY);
+            mac.update(head);
+            mac.update(tail);
+            assert_eq!(mac.clone().finalize(), SIPHASH_2_4_128[len], "length {len}");
+            assert_eq!(mac.clone().verify(&SIPHASH_2_4_128[len]), Ok(()));
+            for bit in 0..128 {
+                let mut tag = SIPHASH_2_4_128[len];
+                tag[bit / 8] ^= 1 << (bit % 8);
+                assert_eq!(mac.clone().verify(&tag), Err(siphash::MacError));
+            }
+        }
+    }
+
+    #[test]
+    pub fn siphash_mac_debug_tests() {
+        let mut mac = SipMac::<2, 4>::new(&REFERENCE_KEY);
+        let mut mac128 = SipMac128::<2, 4>::new(&REFERENCE_KEY);
+        mac.update(&REFERENCE_MESSAGE[..11]);
+        mac128.update(&REFERENCE_MESSAGE[..11]);
+
+        let debug = std::format!("{mac:?}");
+        let debug128 = std::format!("{mac128:?}");
+        assert_eq!(debug, "SipMac { .. }");
+        assert_eq!(debug128, "SipMac128 { .. }");
+        for key in [
+            std::format!("{REFERENCE_KEY}"),
+            std::format!("{REFERENCE_KEY:?}"),
+            std::format!("{REFERENCE_K0}"),
+            std::format!("{REFERENCE_K1}"),
+        ] {
+            assert!(!debug.contains(&key), "{debug} contains {key}");
+            assert!(!debug128.contains(&key), "{debug128} contains {key}");
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    fn check_digest_mac<M>(vectors: &[impl AsRef<[u8]>; 64])
+    where
+        M: digest::Mac + digest::KeyInit + digest::FixedOutputReset + Clone,
+        M: digest::common::KeySizeUser<KeySize = digest::consts::U16>,
+    {
+        use digest::Mac;
+
+        let key = REFERENCE_KEY.to_bytes();
+        assert!(<M as digest::KeyInit>::new_from_slice(&key[..15]).is_err());
+
+        let mut reused = <M as digest::KeyInit>::new_from_slice(&key).unwrap();
+        for (len, expected) in vectors.iter().enumerate() {
+            let expected = expected.as_ref();
+            let msg = &REFERENCE_MESSAGE[..len];
+
+            let mac = <M as digest::KeyInit>::new(&key.into()).chain_update(msg);
+            assert_eq!(
+                &mac.clone().finalize().into_bytes()[..],
+                expected,
+                "length {len}"
+            );
+            assert!(mac.clone().verify_slice(expected).is_ok());
+            let mut wrong = expected.to_vec();
+            let index = len % wrong.len();
+            wrong[index] ^= 1;
+            assert!(mac.verify_slice(&wrong).is_err());
+
+            Mac::update(&mut reused, msg);
+            assert_eq!(&reused.finalize_reset().into_bytes()[..], expected);
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    pub fn siphash_digest_mac_tests() {
+        check_digest_mac::<SipMac<2, 4>>(&SIPHASH_2_4_64);
+        check_digest_mac::<SipMac128<2, 4>>(&SIPHASH_2_4_128);
+    }
+
+    /// A writer or reader that accepts or produces at most `limit` bytes per call
+    #[cfg(feature = "std")]
+    struct Trickle<T> {
+        inner: T,
+        limit: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl<W: std::io::Write> std::io::Write for Trickle<W> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.inner.write(&buf[..buf.len().min(self.limit)])
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: std::io::Read> std::io::Read for Trickle<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = buf.len().min(self.limit);
+            self.inner.read(&mut buf[..len])
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn siphash_io_tests() {
+        use crate::io::{hash_reader, hash_reader128, HashingReader, HashingWriter};
+        use std::io::{IoSlice, Read, Write};
+        use std::vec::Vec;
+
+        let mut rng = TestRng(0xa54ff53a5f1d36f1);
+        for len in 0..64 {
+            let msg = &REFERENCE_MESSAGE[..len];
+            let limit = 1 + rng.below(9);
+
+            let reader = Trickle { inner: msg, limit };
+            assert_eq!(
+                hash_reader::<2, 4, _>(&REFERENCE_KEY, reader)
+                    .unwrap()
+                    .to_le_bytes(),
+                SIPHASH_2_4_64[len]
+            );
+            let reader = Trickle { inner: msg, limit };
+            assert_eq!(
+                hash_reader128::<2, 4, _>(&REFERENCE_KEY, reader)
+                    .unwrap()
+                    .to_le_bytes(),
+                SIPHASH_2_4_128[len]
+            );
+
+            let (a, rest) = msg.split_at(rng.below(len + 1));
+            let (b, c) = rest.split_at(rng.below(rest.len() + 1));
+            let slices = [IoSlice::new(a), IoSlice::new(b), IoSlice::new(c)];
+            let mut hasher = SipHasher::<2, 4>::from_key(REFERENCE_KEY);
+            assert_eq!(hasher.write_vectored(&slices).unwrap(), len);
+            assert_eq!(hasher.finish().to_le_bytes(), SIPHASH_2_4_64[len]);
+
+            let mut raw = RawSipHasher::<2, 4>::from_keys(REFERENCE_K0, REFERENCE_K1);
+            let mut expected = raw;
+            Write::write_all(&mut raw, msg).unwrap();
+            expected.update_from_bytes(msg);
+            assert_eq!(raw.finish(), expected.finish());
+
+            let hasher = SipHasher::<2, 4>::from_key(REFERENCE_KEY);
+            let inner = Trickle {
+                inner: Vec::new(),
+                limit,
+            };
+            let mut writer = HashingWriter::new(inner, hasher);
+            writer.write_all(a).unwrap();
+            // A short vectored write, of which only the accepted prefix is hashed
+            let n = writer
+                .write_vectored(&[IoSlice::new(b), IoSlice::new(c)])
+                .unwrap();
+            let written = writer.get_ref().inner.len();
+            assert_eq!(written, a.len() + n);
+            writer.write_all(&msg[written..]).unwrap();
+            assert_eq!(writer.finish().to_le_bytes(), SIPHASH_2_4_64[len]);
+            assert_eq!(writer.into_inner().0.inner, msg);
+
+            let hasher = SipHasher128::<2, 4>::from_key(REFERENCE_KEY);
+            let mut reader = HashingReader::new(Trickle { inner: msg, limit }, hasher);
+            let mut read = Vec::new();
+            reader.read_to_end(&mut read).unwrap();
+            assert_eq!(read, msg);
+            assert_eq!(
+                reader.hasher().finish128().to_le_bytes(),
+                SIPHASH_2_4_128[len]
+            );
+        }
+    }
+
+    fn check_siphash128_streaming<const C: usize, const D: usize>(vectors: &[[u8; 16]; 64]) {
+        check_vectors_streaming(
+            vectors,
+            || SipHasher128::<C, D>::new_with_keys(REFERENCE_K0, REFERENCE_K1),
+            |hasher| hasher.finish128().to_le_bytes(),
+        );
+    }
+
+    #[test]
+    pub fn siphash_2_4_64_tests() {
+        check_siphash::<2, 4>(&SIPHASH_2_4_64);
+    }
+
+    #[test]
+    pub fn siphash_2_4_128_tests() {
+        check_siphash128::<2, 4>(&SIPHASH_2_4_128);
+    }
+
+    #[test]
+    pub fn siphash_1_3_64_tests() {
+        check_siphash::<1, 3>(&SIPHASH_1_3_64);
+    }
+
+    #[test]
+    pub fn siphash_1_3_128_tests() {
+        check_siphash128::<1, 3>(&SIPHASH_1_3_128);
+    }
+
+    #[test]
+    pub fn siphash_4_8_64_tests() {
+        check_siphash::<4, 8>(&SIPHASH_4_8_64);
+    }
+
+    #[test]
+    pub fn siphash_4_8_128_tests() {
+        check_siphash128::<4, 8>(&SIPHASH_4_8_128);
+    }
+
+    /// Checks the reference vectors against [`SipHasher`], and SipHash-128 computed directly on the state, using the backend `B`
+    fn check_backend<B: SipBackend>() {
+        check_vectors_streaming(
+            &SIPHASH_2_4_64,
+            || SipHasher::<2, 4, B>::new_with_backend_keys(REFERENCE_K0, REFERENCE_K1),
+            |hasher| hasher.finish().to_le_bytes(),
+        );
+        for (len, expected) in SIPHASH_2_4_128.iter().enumerate() {
+            let msg = &REFERENCE_MESSAGE[..len];
+            let mut state = B::from_keys128(REFERENCE_K0, REFERENCE_K1);
+            let (chunks, rem) = msg.as_chunks::<8>();
+            state.update_chunks::<2>(chunks);
+            let mut last = [0u8; 8];
+            last[..rem.len()].copy_from_slice(rem);
+            last[7] = len as u8;
+            state.update_and_round::<2>(u64::from_le_bytes(last));
+            assert_eq!(
+                *expected,
+                state.update_and_final128::<4>().to_le_bytes(),
+                "length {len}"
+            );
+        }
+        let hasher = RawSipHasher::<2, 4, B>::from_backend_keys(REFERENCE_K0, REFERENCE_K1);
+        assert_eq!(
+            hasher.finish(),
+            RawSipHasher::<2, 4>::from_keys(REFERENCE_K0, REFERENCE_K1).finish()
+        );
+    }
+
+    /// A backend that only implements the required methods of [`SipBackend`]
+    #[derive(Copy, Clone, Debug)]
+    struct MinimalBackend(backend::Generic);
+
+    impl SipBackend for MinimalBackend {
+        fn from_state(state: [u64; 4]) -> Self {
+            Self(backend::Generic::from_state(state))
+        }
+
+        fn inspect_state(&self) -> [u64; 4] {
+            self.0.inspect_state()
+        }
+
+        fn update_before_rounds(&mut self, word: u64) {
+            self.0.update_before_rounds(word)
+        }
+
+        fn update_after_rounds(&mut self, word: u64) {
+            self.0.update_after_rounds(word)
+        }
+
+        fn update_before_final(&mut self) {
+            self.0.update_before_final()
+        }
+
+        fn finish(self) -> u64 {
+            self.0.finish()
+        }
+
+        fn round(&mut self) {
+            self.0.round()
+        }
+    }
+
+    /// Runs `$check::<B>()` for every [`SipBackend`] available on the target
+    macro_rules! check_all_backends {
+        ($check:ident) => {
+            $check::<SipHashState>();
+            $check::<backend::Generic>();
+            $check::<MinimalBackend>();
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"
+            ))]
+            $check::<backend::Sse>();
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx2"
+            ))]
+            $check::<backend::Avx2>();
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx512f",
+                target_feature = "avx512vl"
+            ))]
+            $check::<backend::Avx512>();
+            // Miri does not implement the NEON shift and `XAR` intrinsics used by the round
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon", not(miri)))]
+            $check::<backend::Neon>();
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+            $check::<backend::Simd128>();
+        };
+    }
+
+    #[test]
+    pub fn siphash_backend_tests() {
+        check_all_backends!(check_backend);
+    }
+
+    #[test]
+    pub fn siphash_streaming_tests() {
+        check_siphash_streaming::<2, 4>(&SIPHASH_2_4_64);
+        check_siphash_streaming::<1, 3>(&SIPHASH_1_3_64);
+        check_siphash_streaming::<4, 8>(&SIPHASH_4_8_64);
+        check_siphash128_streaming::<2, 4>(&SIPHASH_2_4_128);
+        check_siphash128_streaming::<1, 3>(&SIPHASH_1_3_128);
+        check_siphash128_streaming::<4, 8>(&SIPHASH_4_8_128);
+    }
+
+    /// A small deterministic generator for differential tests (splitmix64)
+    struct TestRng(u64);
+
+    impl TestRng {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % (n as u64)) as usize
+        }
+    }
+
+    /// Compares the streaming hashers against the reference model for random keys and messages, written in randomly sized pieces
+    fn check_against_reference<const C: usize, const D: usize>(seed: u64) {
+        let mut rng = TestRng(seed);
+        let mut msg = [0u8; 300];
+        for _ in 0..2000 {
+            let (k0, k1) = (rng.next(), rng.next());
+            let len = rng.below(msg.len() + 1);
+            msg[..len].fill_with(|| rng.next() as u8);
+            let msg = &msg[..len];
+
+            let mut hasher = SipHasher::<C, D>::new_with_keys(k0, k1);
+            let mut hasher128 = SipHasher128::<C, D>::new_with_keys(k0, k1);
+            let mut rest = msg;
+            while !rest.is_empty() {
+                let (l, r) = rest.split_at(rng.below(rest.len().min(20) + 1));
+                hasher.write(l);
+                hasher128.write(l);
+                rest = r;
+            }
+
+            let mut expected = [0u8; 8];
+            reference::siphash(C, D, k0, k1, msg, &mut expected);
+            assert_eq!(
+                expected,
+                hasher.finish().to_le_bytes(),
+                "message {msg:02x?}"
+            );
+
+            let mut expected = [0u8; 16];
+            reference::siphash(C, D, k0, k1, msg, &mut expected);
+            assert_eq!(
+                expected,
+                hasher128.finish128().to_le_bytes(),
+                "message {msg:02x?}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn siphash_reference_model_tests() {
+        check_against_reference::<1, 3>(0x243f6a8885a308d3);
+        check_against_reference::<2, 4>(0x13198a2e03707344);
+        check_against_reference::<4, 8>(0xa4093822299f31d0);
+    }
+
+    /// Checks that `from_state` and `inspect_state` are exact inverses for the backend `B`,
+    /// and that the state after every operation (including each round) matches the reference model
+    fn check_backend_state<B: SipBackend>() {
+        let mut rng = TestRng(0x243f6a8885a308d3);
+        for _ in 0..64 {
+            let state = [rng.next(), rng.next(), rng.next(), rng.next()];
+            assert_eq!(state, B::from_state(state).inspect_state());
+        }
+
+        let (k0, k1) = (rng.next(), rng.next());
+        let mut model = reference::initial_state(k0, k1);
+        let mut state = B::from_keys(k0, k1);
+        assert_eq!(model, state.inspect_state());
+        for _ in 0..8 {
+            let word = rng.next();
+            state.update_before_rounds(word);
+            model[3] ^= word;
+            assert_eq!(model, state.inspect_state());
+            for round in 0..4 {
+                state.round();
+                reference::sipround(&mut model);
+                assert_eq!(model, state.inspect_state(), "round {round}");
+            }
+            state.update_after_rounds(word);
+            model[0] ^= word;
+            assert_eq!(model, state.inspect_state());
+        }
+        state = B::from_state(state.inspect_state());
+
+        let mut state128 = state;
+        let mut model128 = model;
+        state.update_before_final();
+        model[2] ^= 0xff;
+        assert_eq!(model, state.inspect_state());
+        assert_eq!(model.iter().fold(0, |a, v| a ^ v), state.finish());
+
+        state128.update_before_final128();
+        model128[2] ^= 0xee;
+        assert_eq!(model128, state128.inspect_state());
+        state128.update_before_final128_high();
+        model128[1] ^= 0xdd;
+        assert_eq!(model128, state128.inspect_state());
+
+        let mut model = reference::initial_state(k0, k1);
+        model[1] ^= 0xee;
+        assert_eq!(model, B::from_keys128(k0, k1).inspect_state());
+    }
+
+    #[test]
+    pub fn siphash_backend_state_tests() {
+        check_all_backends!(check_backend_state);
+    }
+
+    /// Checks `hash_batch` (which is [`BuildSipHasher::hash_batch`] or an equivalent) against hashing each message on its own, for random batches of messages
+    fn check_hash_batch<const C: usize, const D: usize>(
+        seed: u64,
+        hash_batch: impl Fn(&crate::BuildSipHasher<C, D>, &[&[u8]], &mut [u64]),
+    ) {
+        let mut rng = TestRng(seed);
+        let mut data = [0u8; 512];
+        data.fill_with(|| rng.next() as u8);
+
+        let vectors: [&[u8]; 64] = core::array::from_fn(|len| &REFERENCE_MESSAGE[..len]);
+        let mut out = [0u64; 64];
+        let build = crate::BuildSipHasher::<C, D>::new_with_keys(REFERENCE_K0, REFERENCE_K1);
+        hash_batch(&build, &vectors, &mut out);
+        for (msg, out) in vectors.iter().zip(out) {
+            let mut hasher = build.build_hasher();
+            hasher.write(msg);
+            assert_eq!(hasher.finish(), out, "length {}", msg.len());
+        }
+
+        for _ in 0..200 {
+            let build = crate::BuildSipHasher::<C, D>::new_with_keys(rng.next(), rng.next());
+            let count = rng.below(41);
+            // Batches either have equal lengths, lengths that differ by a few words, or arbitrary lengths
+            let base = rng.below(300);
+            let spread = [1, 24, 200][rng.below(3)];
+            let mut messages: [&[u8]; 40] = [&[]; 40];
+            for msg in &mut messages[..count] {
+                let len = base + rng.below(spread);
+                let start = rng.below(data.len() - len);
+                *msg = &data[start..][..len];
+            }
+            let mut out = [0u64; 40];
+            hash_batch(&build, &messages[..count], &mut out[..count]);
+            for (msg, out) in messages[..count].iter().zip(out) {
+                let mut hasher = build.build_hasher();
+                hasher.write(msg);
+                assert_eq!(hasher.finish(), out, "length {}", msg.len());
+            }
+        }
+    }
+
+    #[test]
+    pub fn siphash_batch_tests() {
+        check_hash_batch::<2, 4>(0x13198a2e03707344, crate::BuildSipHasher::hash_batch);
+        check_hash_batch::<1, 3>(0xa4093822299f31d0, crate::BuildSipHasher::hash_batch);
+    }
+
+    #[test]
+    pub fn siphash_batch_lanes_tests() {
+        // `hash_batch` only groups messages on x86 with AVX2 or AVX-512, so check every lane count directly
+        check_hash_batch::<2, 4>(0x082efa98ec4e6c89, |build, messages, out| {
+            siphash::hash_batch_lanes::<2, 4, 4>(build.initial, messages, out)
+        });
+        check_hash_batch::<2, 4>(0x452821e638d01377, |build, messages, out| {
+            siphash::hash_batch_lanes::<2, 4, 8>(build.initial, messages, out)
+        });
+        check_hash_batch::<1, 3>(0xbe5466cf34e90c6c, |build, messages, out| {
+            siphash::hash_batch_lanes::<1, 3, 4>(build.initial, messages, out)
+        });
+        check_hash_batch::<1, 3>(0xc0ac29b7c97c50dd, |build, messages, out| {
+            siphash::hash_batch_lanes::<1, 3, 8>(build.initial, messages, out)
+        });
+    }
+
+    #[test]
+    pub fn siphash_build_hasher_tests() {
+        let mut rng = TestRng(0xc0ac29b7c97c50dd);
+        let (k0, k1) = (rng.next(), rng.next());
+        let build = crate::BuildSipHasher::<2, 4>::new_with_keys(k0, k1);
+        for _ in 0..100 {
+            let v = rng.next();
+            assert_eq!(build.hash_one(v as u32), build.hash_u32(v as u32));
+            assert_eq!(build.hash_one(v), build.hash_u64(v));
+            assert_eq!(build.hash_one(v as usize), build.hash_usize(v as usize));
+
+            let mut expected = [0u8; 8];
+            reference::siphash(2, 4, k0, k1, &v.to_le_bytes(), &mut expected);
+            assert_eq!(u64::from_le_bytes(expected), build.hash_u64(v));
+        }
+
+        let mut hasher = build.build_hasher();
+        hasher.write(b"discarded by reset");
+        build.reset(&mut hasher);
+        hasher.write(b"message");
+
+        let mut expected = SipHasher::<2, 4>::new_with_keys(k0, k1);
+        expected.write(b"message");
+        assert_eq!(expected.finish(), hasher.finish());
+    }
+
+    #[test]
+    pub fn siphash_lanes_tests() {
+        let mut rng = TestRng(0x082efa98ec4e6c89);
+        let k0: [u64; 8] = core::array::from_fn(|_| rng.next());
+        let k1: [u64; 8] = core::array::from_fn(|_| rng.next());
+
+        let mut lanes = crate::SipHashStateX8::from_keys128(k0, k1);
+        let mut states: [SipHashState; 8] =
+            core::array::from_fn(|i| SipHashState::from_keys128(k0[i], k1[i]));
+        for _ in 0..16 {
+            let words: [u64; 8] = core::array::from_fn(|_| rng.next());
+            lanes.update_and_round::<2>(words);
+            for (state, word) in states.iter_mut().zip(words) {
+                state.update_and_round::<2>(word);
+            }
+        }
+        assert_eq!(
+            lanes.inspect_states(),
+            states.map(|state| state.inspect_state())
+        );
+        assert_eq!(
+            lanes.inspect_states(),
+            crate::SipHashStateX8::from_states(lanes.inspect_states()).inspect_states()
+        );
+        for (i, state) in states.iter().enumerate() {
+            assert_eq!(lanes.lane(i).inspect_state(), state.inspect_state());
+        }
+        assert_eq!(
+            lanes.update_and_final128::<4>(),
+            states.map(|state| state.update_and_final128::<4>())
+        );
+    }
+
+    #[test]
+    pub fn siphash_multi_key_tests() {
+        let mut rng = TestRng(0xbe5466cf34e90c6c);
+        let k0: [u64; 5] = core::array::from_fn(|_| rng.next());
+        let k1: [u64; 5] = core::array::from_fn(|_| rng.next());
+        let data: [u8; 64] = core::array::from_fn(|i| i as u8);
+
+        for len in 0..data.len() {
+            let mut multi = MultiKeySipHasher::<2, 4, 5>::new_with_keys(k0, k1);
+            let mut hashers: [SipHasher<2, 4>; 5] =
+                core::array::from_fn(|i| SipHasher::new_with_keys(k0[i], k1[i]));
+            for part in data[..len].chunks(1 + len % 11) {
+                multi.write(part);
+                for hasher in &mut hashers {
+                    hasher.write(part);
+                }
+            }
+            assert_eq!(multi.finish(), hashers.map(|hasher| hasher.finish()));
+        }
+    }
+
+    #[test]
+    pub fn siphash_words_tests() {
+        let mut rng = TestRng(0x3f84d5b5b5470917);
+        let (k0, k1) = (rng.next(), rng.next());
+        let words: [u64; 24] = core::array::from_fn(|_| rng.next());
+        let bytes = words.map(u64::to_le_bytes);
+        let bytes = bytes.as_flattened();
+
+        for prefix in 0..8 {
+            for len in 0..words.len() {
+                let mut hasher = SipHasher::<2, 4>::new_with_keys(k0, k1);
+                hasher.write(&bytes[..prefix]);
+                let mut expected = hasher;
+                hasher.write_u64s(&words[..len]);
+                hasher.write(&bytes[..prefix]);
+                expected.write(&bytes[..len * 8]);
+                expected.write(&bytes[..prefix]);
+                assert_eq!(expected.finish(), hasher.finish(), "{prefix} + {len} words");
+            }
+        }
+
+        let mut raw = RawSipHasher::<2, 4>::from_keys(k0, k1);
+        let mut expected = raw;
+        raw.update_words(&words);
+        for word in words {
+            expected.update(word);
+        }
+        assert_eq!(expected.finish(), raw.finish());
+    }
+
+    /// Writes an integer chosen by `kind` to `hasher` with the integer writes, and its little-endian bytes (widened to 64 bits for `usize` and `isize`) to `bytewise`
+    fn write_integer<H: Hasher>(hasher: &mut H, bytewise: &mut H, kind: usize, v: u128) {
+        match kind {
+            0 => hasher.write_u8(v as u8),
+            1 => hasher.write_u16(v as u16),
+            2 => hasher.write_u32(v as u32),
+            3 => hasher.write_u64(v as u64),
+            4 => hasher.write_u128(v),
+            5 => hasher.write_usize(v as usize),
+            6 => hasher.write_i8(v as i8),
+            7 => hasher.write_i16(v as i16),
+            8 => hasher.write_i32(v as i32),
+            9 => hasher.write_i64(v as i64),
+            10 => hasher.write_i128(v as i128),
+            _ => hasher.write_isize(v as isize),
+        }
+        match kind {
+            5 => bytewise.write(&(v as usize as u64).to_le_bytes()),
+            11 => bytewise.write(&(v as isize as i64).to_le_bytes()),
+            _ => match kind % 6 {
+                0 => bytewise.write(&(v as u8).to_le_bytes()),
+                1 => bytewise.write(&(v as u16).to_le_bytes()),
+                2 => bytewise.write(&(v as u32).to_le_bytes()),
+                3 => bytewise.write(&(v as u64).to_le_bytes()),
+                _ => bytewise.write(&v.to_le_bytes()),
+            },
+        }
+    }
+
+    #[test]
+    pub fn siphash_integer_write_tests() {
+        let mut rng = TestRng(0x452821e638d01377);
+        for _ in 0..2000 {
+            let (k0, k1) = (rng.next(), rng.next());
+            let mut hasher = SipHasher::<2, 4>::new_with_keys(k0, k1);
+            let mut bytewise = hasher;
+            for _ in 0..rng.below(12) {
+                let v = ((rng.next() as u128) << 64) | rng.next() as u128;
+                match rng.below(13) {
+                    12 => {
+                        let bytes = &v.to_le_bytes()[..rng.below(17)];
+                        hasher.write(bytes);
+                        bytewise.write(bytes);
+                    }
+                    kind => write_integer(&mut hasher, &mut bytewise, kind, v),
+                }
+            }
+            assert_eq!(bytewise.finish(), hasher.finish());
+        }
+
+        // The integers are written as their little-endian bytes on every target, which here are the first 30 bytes of the reference message
+        let mut hasher = SipHasher::<2, 4>::new_with_keys(REFERENCE_K0, REFERENCE_K1);
+        (
+            0x0100u16,
+            0x05040302u32,
+            0x0d0c0b0a09080706u64,
+            0x1d1c1b1a191817161514131211100f0eu128,
+        )
+            .hash(&mut hasher);
+        assert_eq!(SIPHASH_2_4_64[30], hasher.finish().to_le_bytes());
+    }
+
+    #[test]
+    pub fn siphash_aligned_load_tests() {
+        use crate::siphash::{load_words_aligned, BULK_MIN_WORDS};
+
+        #[repr(align(8))]
+        struct Aligned([u8; 8 * (3 * BULK_MIN_WORDS + 1)]);
+
+        let mut rng = TestRng(0x6a09e667f3bcc908);
+        let buf = Aligned(core::array::from_fn(|_| rng.next() as u8));
+        for offset in 0..8 {
+            for len in 0..=3 * BULK_MIN_WORDS {
+                let (words, _) = buf.0[offset..][..len * 8].as_chunks::<8>();
+                let mut loaded = std::vec::Vec::new();
+                load_words_aligned(words, |word| loaded.push(word));
+                let expected: std::vec::Vec<u64> =
+                    words.iter().map(|&word| u64::from_le_bytes(word)).collect();
+                assert_eq!(expected, loaded, "offset {offset}, {len} words");
+            }
+        }
+    }
+
+    const KEYWORDS: [&str; 38] = [
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while",
+    ];
+
+    static KEYWORD_MAP: SipPhfMap<&str, usize> = crate::sip_phf_map! {
+        "as" => 0, "async" => 1, "await" => 2, "break" => 3, "const" => 4, "continue" => 5,
+        "crate" => 6, "dyn" => 7, "else" => 8, "enum" => 9, "extern" => 10, "false" => 11,
+        "fn" => 12, "for" => 13, "if" => 14, "impl" => 15, "in" => 16, "let" => 17, "loop" => 18,
+        "match" => 19, "mod" => 20, "move" => 21, "mut" => 22, "pub" => 23, "ref" => 24,
+        "return" => 25, "self" => 26, "Self" => 27, "static" => 28, "struct" => 29, "super" => 30,
+        "trait" => 31, "true" => 32, "type" => 33, "unsafe" => 34, "use" => 35, "where" => 36,
+        "while" => 37,
+    };
+
+    #[test]
+    pub fn siphash_phf_tests() {
+        assert_eq!(KEYWORD_MAP.len(), KEYWORDS.len());
+        for (i, keyword) in KEYWORDS.iter().enumerate() {
+            assert_eq!(KEYWORD_MAP.get_entry(*keyword), Some((keyword, &i)));
+        }
+        for missing in ["", "a", "Fn", "fns", "whilst", "macro_rules", "union"] {
+            assert_eq!(KEYWORD_MAP.get(missing), None, "{missing}");
+        }
+
+        static BYTES: SipPhfMap<&[u8], u8> = crate::sip_phf_map! {
+            b"\0" => 0, b"\xff\xfe" => 1, b"" => 2,
+        };
+        assert_eq!(BYTES.get(&b"\xff\xfe"[..]), Some(&1));
+        assert_eq!(BYTES.get(&b""[..]), Some(&2));
+        assert!(!BYTES.contains_key(&b"\xff"[..]));
+
+        static EMPTY: SipPhfMap<&str, ()> = crate::sip_phf_map! {};
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.get("fn"), None);
+
+        // Generated keys, to exercise the search with many full buckets
+        static GENERATED_KEYS: [[u8; 8]; 1000] = {
+            let mut keys = [[0u8; 8]; 1000];
+            let mut i = 0;
+            while i < 1000 {
+                keys[i] = (i as u64).wrapping_mul(0x9e3779b97f4a7c15).to_le_bytes();
+                i += 1;
+            }
+            keys
+        };
+        static GENERATED_LAYOUT: phf::PhfLayout<1000, { phf::bucket_count(1000) }> = {
+            let mut keys = [&[] as &[u8]; 1000];
+            let mut i = 0;
+            while i < 1000 {
+                keys[i] = &GENERATED_KEYS[i];
+                i += 1;
+            }
+            phf::generate(&keys)
+        };
+        let entries: &'static [([u8; 8], usize)] = std::vec::Vec::leak(
+            GENERATED_KEYS
+                .iter()
+                .enumerate()
+                .map(|(i, &key)| (key, i))
+                .collect(),
+        );
+        let map = SipPhfMap::from_layout(&GENERATED_LAYOUT, entries);
+        for (i, key) in GENERATED_KEYS.iter().enumerate() {
+            assert_eq!(map.get(key), Some(&i));
+        }
+        assert_eq!(map.get(&[0xff; 8]), None);
+
+        const HASH: u64 = crate::siphash!(1, 3; REFERENCE_K0, REFERENCE_K1, "fn");
+        let mut hasher = SipHasher::<1, 3>::new_with_keys(REFERENCE_K0, REFERENCE_K1);
+        hasher.write(b"fn");
+        assert_eq!(hasher.finish(), HASH);
+    }
+
+    #[test]
+    pub fn halfsiphash_2_4_tests() {
+        check_vectors_streaming(
+            &HALFSIPHASH_2_4_32,
+            || HalfSipHasher::<2, 4>::new_with_keys(HALF_REFERENCE_K0, HALF_REFERENCE_K1),
+            |hasher| hasher.finish32().to_le_bytes(),
+        );
+    }
+
+    #[test]
+    pub fn halfsiphash_2_4_64_tests() {
+        check_vectors_streaming(
+            &HALFSIPHASH_2_4_64,
+            || HalfSipHasher64::<2, 4>::new_with_keys(HALF_REFERENCE_K0, HALF_REFERENCE_K1),
+            |hasher| hasher.finish64().to_le_bytes(),
+        );
+    }
+
+    #[test]
+    pub fn halfsiphash_integer_write_tests() {
+        let mut rng = TestRng(0x3f84d5b5b5470917);
+        for _ in 0..2000 {
+            let (k0, k1) = (rng.next() as u32, rng.next() as u32);
+            let mut hasher = HalfSipHasher::<2, 4>::new_with_keys(k0, k1);
+            let mut bytewise = hasher;
+            let mut hasher64 = HalfSipHasher64::<2, 4>::new_with_keys(k0, k1);
+            let mut bytewise64 = hasher64;
+            for _ in 0..rng.below(12) {
+                let v = ((rng.next() as u128) << 64) | rng.next() as u128;
+                match rng.below(13) {
+                    12 => {
+                        let bytes = &v.to_le_bytes()[..rng.below(17)];
+                        hasher.write(bytes);
+                        bytewise.write(bytes);
+                        hasher64.write(bytes);
+                        bytewise64.write(bytes);
+                    }
+                    kind => {
+                        write_integer(&mut hasher, &mut bytewise, kind, v);
+                        write_integer(&mut hasher64, &mut bytewise64, kind, v);
+                    }
+                }
+            }
+            assert_eq!(bytewise.finish(), hasher.finish());
+            assert_eq!(bytewise64.finish(), hasher64.finish());
+        }
+
+        // The first 30 bytes of the reference message, written as integers
+        let mut hasher = HalfSipHasher::<2, 4>::new_with_keys(HALF_REFERENCE_K0, HALF_REFERENCE_K1);
+        (
+            0x0100u16,
+            0x05040302u32,
+            0x0d0c0b0a09080706u64,
+            0x1d1c1b1a191817161514131211100f0eu128,
+        )
+            .hash(&mut hasher);
+        assert_eq!(HALFSIPHASH_2_4_32[30], hasher.finish32().to_le_bytes());
+    }
+
+    #[test]
+    pub fn halfsiphash_build_hasher_tests() {
+        let build =
+            crate::BuildHalfSipHasher::<2, 4>::new_with_keys(HALF_REFERENCE_K0, HALF_REFERENCE_K1);
+        let mut hasher = build.build_hasher();
+        hasher.write(b"discarded by reset");
+        build.reset(&mut hasher);
+        hasher.write(&REFERENCE_MESSAGE[..11]);
+        assert_eq!(HALFSIPHASH_2_4_32[11], hasher.finish32().to_le_bytes());
+    }
+
+    /// Hashes each value with both hashers through its [`Hash`] impl, and checks that the results are equal
+    macro_rules! assert_hashes_match {
+        ($new_ours:expr, $new_std:expr; $($val:expr),* $(,)?) => {
+            $({
+                let mut ours = $new_ours;
+                let mut std = $new_std;
+                $val.hash(&mut ours);
+                $val.hash(&mut std);
+                assert_eq!(std.finish(), ours.finish(), "{}", stringify!($val));
+            })*
+        };
+    }
+
+    macro_rules! assert_std_compat {
+        ($new_ours:expr, $new_std:expr) => {
+            assert_hashes_match!($new_ours, $new_std;
+                0x5au8, 0x5a5au16, 0xdeadbeefu32, 0x0123456789abcdefu64, u128::MAX / 3, usize::MAX - 7,
+                -3i8, -300i16, -70000i32, i64::MIN, -(1i128 << 100), -1isize,
+                true, 'λ', (), "", "hello", "a longer string that spans several words", String::from("owned"),
+                [0u8; 0].as_slice(), b"bytes!!".as_slice(), [1u16, 2, 3].as_slice(), [7u32; 9], [u64::MAX; 3].as_slice(),
+                vec!["a", "bc", "def"], ("tuple", 1u8, 2u64), Some(17u32), None::<u32>, [[1u8, 2], [3, 4]],
+            );
+        };
+    }
+
+    #[test]
+    pub fn std_compat_siphash_1_3_tests() {
+        assert_std_compat!(
+            StdCompatSipHasher::<1, 3>::new(),
+            std::hash::DefaultHasher::new()
+        );
+        assert_std_compat!(
+            StdCompatSipHasher::<1, 3>::default(),
+            std::hash::DefaultHasher::default()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    pub fn std_compat_siphash_2_4_tests() {
+        assert_std_compat!(
+            StdCompatSipHasher::<2, 4>::new_with_keys(REFERENCE_K0, REFERENCE_K1),
+            core::hash::SipHasher::new_with_keys(REFERENCE_K0, REFERENCE_K1)
+        );
+        assert_std_compat!(
+            StdCompatSipHasher::<2, 4>::new_with_keys(0, 0),
+            core::hash::SipHasher::new()
+        );
+    }
 }
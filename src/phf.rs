@@ -0,0 +1,400 @@
+//! Module providing static perfect hash maps, generated at compile time and looked up with [`SipHasher<1, 3>`][crate::SipHasher].
+//!
+//! The maps use the hash-and-displace construction (the same one used by the `phf` crate). Each key is hashed once, and the hash is split into a bucket index and two coefficients.
+//! The generator searches for a pair of SipHash keys, and a pair of displacement values for each bucket, that place every key into a distinct slot.
+//! A lookup then hashes the key, reads the displacements of its bucket, and compares the key against the single entry in the resulting slot.
+//!
+//! The [`sip_phf_map!`][crate::sip_phf_map!] macro runs [`generate`] in a `const` item, so the whole table is built by the compiler, and the resulting [`SipPhfMap`] can be stored in a `static`.
+
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hasher};
+
+use crate::siphash::const_hash;
+use crate::BuildSipHasher;
+
+/// A key type that can be stored in (or looked up in) a [`SipPhfMap`].
+///
+/// Keys are hashed as the bytes returned by [`PhfKey::key_bytes`], with a single call to [`Hasher::write`], rather than by their [`Hash`][core::hash::Hash] impl.
+/// This is what allows [`generate`] to compute the same hashes at compile time, and makes the hashes independent of the endianness and pointer width of the target.
+pub trait PhfKey {
+    /// Returns the bytes that are hashed to find `self` in a [`SipPhfMap`]
+    fn key_bytes(&self) -> &[u8];
+}
+
+impl PhfKey for str {
+    fn key_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl PhfKey for [u8] {
+    fn key_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const N: usize> PhfKey for [u8; N] {
+    fn key_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<T: ?Sized + PhfKey> PhfKey for &T {
+    fn key_bytes(&self) -> &[u8] {
+        T::key_bytes(self)
+    }
+}
+
+/// Marks a slot that has not been assigned a key by [`generate`]
+const EMPTY: u32 = u32::MAX;
+
+/// The maximum number of SipHash keys that [`generate`] tries before giving up
+const MAX_ATTEMPTS: usize = 64;
+
+/// The number of values of the first displacement that [`generate`] tries for each bucket, before trying the next SipHash keys.
+///
+/// The second displacement is added to the slot directly, so it only needs to range up to the number of slots.
+const MAX_D1: u32 = 16;
+
+/// The SipHash keys, displacements, and slot assignments found by [`generate`] for `N` keys and `B` buckets.
+#[derive(Copy, Clone, Debug)]
+pub struct PhfLayout<const N: usize, const B: usize> {
+    k0: u64,
+    k1: u64,
+    disps: [(u32, u32); B],
+    slots: [u32; N],
+}
+
+/// Returns the number of buckets used by [`sip_phf_map!`][crate::sip_phf_map!] for `len` keys.
+///
+/// This averages 3 keys per bucket. Larger buckets would make the displacement table smaller,
+///  but the last of them are much harder to place once most of the slots are taken, which makes the search too slow for compile time.
+pub const fn bucket_count(len: usize) -> usize {
+    len.div_ceil(3)
+}
+
+/// Splits the hash of a key into the bucket index and the two displacement coefficients
+#[inline]
+const fn split(hash: u64) -> (u32, u32, u32) {
+    let f1 = hash as u32;
+    let f2 = (hash >> 32) as u32;
+    (f1 ^ f2.rotate_left(16), f1, f2)
+}
+
+/// Computes the slot (before reduction modulo the number of slots) of a key with the coefficients `f1` and `f2` in a bucket with the displacements `d1` and `d2`
+#[inline]
+const fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
+
+/// Returns `true` if `a` and `b` contain the same bytes
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Searches for a layout of `keys` into `B` buckets, where every key is hashed with SipHash-1-3 using the keys `k0` and `k1`
+const fn try_generate<const N: usize, const B: usize>(
+    k0: u64,
+    k1: u64,
+    keys: &[&[u8]; N],
+) -> Option<PhfLayout<N, B>> {
+    let mut hashes = [0u64; N];
+    let mut sizes = [0usize; B];
+    let mut i = 0;
+    while i < N {
+        hashes[i] = const_hash::<1, 3>(k0, k1, keys[i]);
+        sizes[split(hashes[i]).0 as usize % B] += 1;
+        i += 1;
+    }
+
+    // Counting sort the keys by bucket, so that the keys of bucket `b` are `members[starts[b]..starts[b] + sizes[b]]`
+    let mut starts = [0usize; B];
+    let mut total = 0;
+    let mut b = 0;
+    while b < B {
+        starts[b] = total;
+        total += sizes[b];
+        b += 1;
+    }
+    let mut members = [0usize; N];
+    let mut coeffs = [(0u32, 0u32); N];
+    let mut next = starts;
+    let mut i = 0;
+    while i < N {
+        let (g, f1, f2) = split(hashes[i]);
+        let bucket = g as usize % B;
+        members[next[bucket]] = i;
+        coeffs[next[bucket]] = (f1, f2);
+        next[bucket] += 1;
+        i += 1;
+    }
+
+    // Equal keys have equal hashes, so only keys in the same bucket with the same full hash need to be compared.
+    // Distinct keys with the same hash can never be placed in distinct slots, so those need another pair of SipHash keys.
+    let mut b = 0;
+    while b < B {
+        let mut j = starts[b];
+        while j < starts[b] + sizes[b] {
+            let mut l = j + 1;
+            while l < starts[b] + sizes[b] {
+                if hashes[members[j]] == hashes[members[l]] {
+                    assert!(
+                        !bytes_eq(keys[members[j]], keys[members[l]]),
+                        "duplicate key in perfect hash map"
+                    );
+                    return None;
+                }
+                l += 1;
+            }
+            j += 1;
+        }
+        b += 1;
+    }
+
+    // Place the largest buckets first, while most of the slots are still free.
+    // This is another counting sort, by decreasing size, where `by_size[s - 1]` starts out as the number of buckets of size `s`
+    let mut by_size = [0usize; N];
+    let mut b = 0;
+    while b < B {
+        if sizes[b] > 0 {
+            by_size[sizes[b] - 1] += 1;
+        }
+        b += 1;
+    }
+    let mut filled = 0;
+    let mut s = N;
+    while s > 0 {
+        let count = by_size[s - 1];
+        by_size[s - 1] = filled;
+        filled += count;
+        s -= 1;
+    }
+    let mut order = [0usize; B];
+    let mut b = 0;
+    while b < B {
+        if sizes[b] > 0 {
+            order[by_size[sizes[b] - 1]] = b;
+            by_size[sizes[b] - 1] += 1;
+        }
+        b += 1;
+    }
+
+    let mut disps = [(0u32, 0u32); B];
+    let mut slots = [EMPTY; N];
+    // `claimed[slot] == generation` if the slot was taken by another key of the bucket with the displacements currently being tried
+    let mut claimed = [0usize; N];
+    let mut generation = 0;
+
+    let mut b = 0;
+    while b < filled {
+        let bucket = order[b];
+        let first = starts[bucket];
+        let len = sizes[bucket];
+
+        // Bound the search, and give up on these SipHash keys if no displacements fit, as `phf_generator` does.
+        // Every value of `d2` is tried for each `d1`, so a bucket with a single key always fits while there is a free slot.
+        let mut found = false;
+        let mut d1 = 0;
+        'search: while d1 < MAX_D1 {
+            let mut d2 = 0;
+            while d2 < N as u32 {
+                generation += 1;
+                let mut fits = true;
+                let mut j = 0;
+                while j < len {
+                    let (f1, f2) = coeffs[first + j];
+                    let slot = (displace(f1, f2, d1, d2) % N as u32) as usize;
+                    if slots[slot] != EMPTY || claimed[slot] == generation {
+                        fits = false;
+                        break;
+                    }
+                    claimed[slot] = generation;
+                    j += 1;
+                }
+
+                if fits {
+                    let mut j = 0;
+                    while j < len {
+                        let (f1, f2) = coeffs[first + j];
+                        let slot = (displace(f1, f2, d1, d2) % N as u32) as usize;
+                        slots[slot] = members[first + j] as u32;
+                        j += 1;
+                    }
+                    disps[bucket] = (d1, d2);
+                    found = true;
+                    break 'search;
+                }
+                d2 += 1;
+            }
+            d1 += 1;
+        }
+
+        if !found {
+            return None;
+        }
+        b += 1;
+    }
+
+    Some(PhfLayout {
+        k0,
+        k1,
+        disps,
+        slots,
+    })
+}
+
+/// Generates a perfect hash layout of `keys` into `B` buckets, for use with [`SipPhfMap::from_layout`].
+///
+/// The search is deterministic: the same keys always produce the same layout.
+/// This is a `const fn`, and is intended to be evaluated at compile time, usually by [`sip_phf_map!`][crate::sip_phf_map!].
+///
+/// ## Size limit
+/// The compiler stops constant evaluation that runs for too long with the deny-by-default `long_running_const_eval` lint.
+/// The search for about 4000 short keys (such as identifiers) stays within that limit.
+/// Larger maps can be generated by allowing the lint on the `const` or `static` item that contains the call (or the [`sip_phf_map!`][crate::sip_phf_map!] invocation),
+///  at the cost of a longer build.
+///
+/// ## Panics
+/// Panics if `keys` contains the same key twice, if `B` is `0` while `keys` is not empty, or if no layout is found
+pub const fn generate<const N: usize, const B: usize>(keys: &[&[u8]; N]) -> PhfLayout<N, B> {
+    assert!(B > 0 || N == 0, "a non-empty map needs at least one bucket");
+    assert!(N < EMPTY as usize, "too many keys");
+
+    // Draw the candidate SipHash keys from a splitmix64 sequence
+    let mut seed = 0x243f6a8885a308d3u64;
+    let mut attempt = 0;
+    while attempt < MAX_ATTEMPTS {
+        seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+        let k0 = mix(seed);
+        seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+        let k1 = mix(seed);
+
+        if let Some(layout) = try_generate(k0, k1, keys) {
+            return layout;
+        }
+        attempt += 1;
+    }
+
+    panic!("no perfect hash layout found")
+}
+
+/// The output function of splitmix64
+const fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// A static map with a perfect hash function, which is looked up using [`SipHasher<1, 3>`][crate::SipHasher].
+///
+/// A [`SipPhfMap`] is usually built at compile time by [`sip_phf_map!`][crate::sip_phf_map!], and never allocates.
+/// Each lookup hashes the key once, and compares it against at most one entry.
+#[derive(Clone, Debug)]
+pub struct SipPhfMap<K: 'static, V: 'static> {
+    hasher: BuildSipHasher<1, 3>,
+    disps: &'static [(u32, u32)],
+    slots: &'static [u32],
+    entries: &'static [(K, V)],
+}
+
+impl<K: 'static, V: 'static> SipPhfMap<K, V> {
+    /// Constructs a new [`SipPhfMap`] from a layout generated by [`generate`], and the entries, in the same order as the keys passed to [`generate`].
+    ///
+    /// ## Panics
+    /// Panics if `entries` does not have exactly `N` entries
+    pub const fn from_layout<const N: usize, const B: usize>(
+        layout: &'static PhfLayout<N, B>,
+        entries: &'static [(K, V)],
+    ) -> Self {
+        assert!(
+            entries.len() == N,
+            "the layout was generated for a different number of keys"
+        );
+        Self {
+            hasher: BuildSipHasher::new_with_keys(layout.k0, layout.k1),
+            disps: &layout.disps,
+            slots: &layout.slots,
+            entries,
+        }
+    }
+
+    /// Returns the number of entries in the map
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map has no entries
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the key and value of the entry for `key`, if there is one
+    pub fn get_entry<Q: ?Sized + PhfKey + Eq>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let mut hasher = self.hasher.build_hasher();
+        hasher.write(key.key_bytes());
+        let (g, f1, f2) = split(hasher.finish());
+
+        let (d1, d2) = self.disps[(g % self.disps.len() as u32) as usize];
+        let index = self.slots[(displace(f1, f2, d1, d2) % self.slots.len() as u32) as usize];
+        let (k, v) = &self.entries[index as usize];
+
+        (k.borrow() == key).then_some((k, v))
+    }
+
+    /// Returns the value of the entry for `key`, if there is one
+    pub fn get<Q: ?Sized + PhfKey + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.get_entry(key).map(|(_, v)| v)
+    }
+
+    /// Returns `true` if the map has an entry for `key`
+    pub fn contains_key<Q: ?Sized + PhfKey + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get_entry(key).is_some()
+    }
+
+    /// Returns an iterator over the entries of the map, in the order they were given to [`SipPhfMap::from_layout`]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
+
+/// Builds a [`SipPhfMap`] at compile time from a list of `key => value` entries.
+///
+/// The keys must be constant string literals, byte string literals, or byte slices, and the values must be constant expressions.
+/// The layout is found by [`generate`] in a `const` item, so the result can initialize a `static` (or `const`) of type `SipPhfMap<&'static str, V>` or `SipPhfMap<&'static [u8], V>`.
+///
+/// Compilation fails if the same key is given twice, or (without allowing `long_running_const_eval`) if there are too many keys, as described for [`generate`][crate::phf::generate].
+#[macro_export]
+macro_rules! sip_phf_map {
+    (@unit $key:expr) => {
+        ()
+    };
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        const LEN: usize = <[()]>::len(&[$($crate::sip_phf_map!(@unit $key)),*]);
+        const LAYOUT: $crate::phf::PhfLayout<LEN, { $crate::phf::bucket_count(LEN) }> =
+            $crate::phf::generate(&[$($crate::siphash::KeyBytes($key).get()),*]);
+        $crate::phf::SipPhfMap::from_layout(&LAYOUT, &[$(($key, $value)),*])
+    }};
+}
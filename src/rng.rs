@@ -1,6 +1,6 @@
 //! Module providing a random number generator based on [`RawSipHasher`].
 
-use crate::RawSipHasher;
+use crate::{RawSipHasher, SipKey};
 
 /// [`SiphashRng`] is a random number generator that uses [`RawSipHasher`] to generate a stream of high-quality pseudo-random numbers
 #[derive(Clone, Debug)]
@@ -11,16 +11,7 @@ impl<const C: usize, const D: usize> SiphashRng<C, D> {
     #[cfg(any(doc, feature = "random-state"))]
     #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "random-state")))]
     pub fn from_system_rng() -> Self {
-        let mut bytes = [[0u8; 8]; 2];
-
-        getrandom::fill(bytes.as_flattened_mut()).unwrap();
-
-        let [k0, k1] = bytes;
-
-        Self(RawSipHasher::from_keys(
-            u64::from_ne_bytes(k0),
-            u64::from_ne_bytes(k1),
-        ))
+        Self::from_key(SipKey::from_system_rng())
     }
 
     /// Constructs a new [`SipHashRng`] with the specified keys.
@@ -28,6 +19,11 @@ impl<const C: usize, const D: usize> SiphashRng<C, D> {
         Self(RawSipHasher::from_keys(k0, k1))
     }
 
+    /// Constructs a new [`SiphashRng`] with the specified key.
+    pub const fn from_key(key: SipKey) -> Self {
+        Self::new_with_keys(key.k0(), key.k1())
+    }
+
     /// Constructs a new [`SipHashRng`] from a specified [`RawSipHasher`], which may have already injested arbitrary data
     pub const fn from_raw(raw: RawSipHasher<C, D>) -> Self {
         Self(raw)
@@ -81,6 +77,7 @@ mod imp {
     use rand_core::*;
 
     use crate::rng::SiphashRng;
+    use crate::SipKey;
 
     impl<const C: usize, const D: usize> TryRng for SiphashRng<C, D> {
         type Error = Infallible;
@@ -102,9 +99,7 @@ mod imp {
     impl<const C: usize, const D: usize> SeedableRng for SiphashRng<C, D> {
         type Seed = [u8; 16];
         fn from_seed(seed: Self::Seed) -> Self {
-            let [k0, k1] = unsafe { core::mem::transmute(seed) };
-
-            Self::new_with_keys(k0, k1)
+            Self::from_key(SipKey::from_bytes(seed))
         }
 
         fn from_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
@@ -13,18 +13,84 @@ const SIPHASH_MAG4: u64 = 0x7465646279746573;
     any(target_arch = "x86", target_arch = "x86_64"),
     path = "siphash/x86.rs"
 )]
+#[cfg_attr(target_arch = "aarch64", path = "siphash/aarch64.rs")]
+#[cfg_attr(target_arch = "wasm32", path = "siphash/wasm.rs")]
 #[cfg_attr(
-    not(any(target_arch = "clever", target_arch = "x86", target_arch = "x86_64")),
+    not(any(
+        target_arch = "clever",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "wasm32"
+    )),
     path = "siphash/generic.rs"
 )]
 mod sys;
 
+pub mod backend;
+
+pub use backend::SipBackend;
+
+mod const_eval;
+
+pub use const_eval::{const_hash, const_hash128};
+
+#[doc(hidden)]
+pub use const_eval::KeyBytes;
+
+mod key;
+
+pub use key::{ParseSipKeyError, SipKey};
+
+mod mac;
+
+pub use mac::{MacError, SipMac, SipMac128};
+
+#[cfg(feature = "digest")]
+mod digest;
+
+mod multi;
+
+pub(crate) use multi::hash_batch;
+
+#[cfg_attr(
+    not(all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    )),
+    allow(unused_imports) // only used by the tests without runtime dispatch
+)]
+pub(crate) use multi::hash_batch_lanes;
+pub use multi::{MultiKeySipHasher, SipHashStateX, SipHashStateX4, SipHashStateX8};
+
+/// The minimum number of words written at once for [`SipHashState::update_chunks`] to use the runtime selected round implementation.
+/// Below this, the cost of dispatching outweighs the faster rounds.
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+const DISPATCH_MIN_WORDS: usize = 4;
+
+/// The minimum number of words for [`load_words`] to use [`load_words_aligned`], on targets where it is used.
+/// The aligned path reads the first and last word separately, so shorter inputs gain little from it.
+#[cfg_attr(
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "wasm32"
+    ),
+    allow(dead_code) // only used by the tests on these targets
+)]
+pub(crate) const BULK_MIN_WORDS: usize = 4;
+
 /// Raw state implementation of SipHash.
 /// This wraps a target-dependant state type to provide primitive operations like the SipHash round function.
 ///
 /// Logically, the [`SipHashState`] is the state array `[s0, s1, s2, s3]`.
 /// However no guarantee is made about the precise layout (notably, on many targets, the implementation stores this as `[s0, s2, s1, s3]` to make SIMD operations work nicer).
 /// Use [`SipHashState::inspect_state`] and [`SipHashState::from_state`] as primitives to access the underlying state array.
+/// These are exact inverses on every target, so a state can be serialized on one target and restored on another.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
 pub struct SipHashState(sys::SipHashState);
@@ -37,6 +103,13 @@ impl SipHashState {
         Self(sys::SipHashState::from_keys(k0, k1))
     }
 
+    /// Constructs a new [`SipHashState`] for SipHash-128 from the specified keys.
+    /// This is the same as [`SipHashState::from_keys`], except that `0xee` is additionally xored into s1, as the specification requires for 128-bit output.
+    #[inline]
+    pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+        Self(sys::SipHashState::from_keys128(k0, k1))
+    }
+
     /// Constructs a new [`SipHashState`] from the state array.
     #[inline]
     pub const fn from_state(state: [u64; 4]) -> Self {
@@ -70,11 +143,25 @@ impl SipHashState {
         self.0.update_before_final();
     }
 
+    /// Performs the update operation before the first set of finalization rounds of SipHash-128.
+    /// This mutates the state by xoring `0xee` into s2.
+    #[inline]
+    pub fn update_before_final128(&mut self) {
+        self.0.update_before_final128();
+    }
+
+    /// Performs the update operation before the second set of finalization rounds of SipHash-128, which produces the high word of the result.
+    /// This mutates the state by xoring `0xdd` into s1.
+    #[inline]
+    pub fn update_before_final128_high(&mut self) {
+        self.0.update_before_final128_high();
+    }
+
     /// Consumes the state and produces the final value.
     ///
     /// This xors each word of the state array together and returns them.
-    /// Note that this does not apply the finalization rounds, and you must perform these rounds manually (or call [`update_and_final`]).
-    /// Additionally, this doesn't fixup the order of the u64
+    /// Note that this does not apply the finalization rounds, and you must perform these rounds manually (or call [`Self::update_and_final`]).
+    /// The result is the integer value of the hash. The reference byte output of SipHash is obtained from [`u64::to_le_bytes`].
     #[inline]
     pub fn finish(self) -> u64 {
         self.0.finish()
@@ -96,6 +183,40 @@ impl SipHashState {
         self.update_after_rounds(val);
     }
 
+    /// Injests each of `words`, read as little-endian, as if by [`Self::update_and_round`].
+    ///
+    /// With the `runtime-dispatch` feature, this uses the best round implementation the CPU supports for sufficiently long inputs.
+    #[inline]
+    fn update_chunks<const R: usize>(&mut self, words: &[[u8; 8]]) {
+        #[cfg(all(
+            feature = "runtime-dispatch",
+            any(target_arch = "x86", target_arch = "x86_64")
+        ))]
+        if words.len() >= DISPATCH_MIN_WORDS {
+            return sys::dispatch::update_chunks::<R>(&mut self.0, words);
+        }
+
+        load_words(words, |word| self.update_and_round::<R>(word));
+    }
+
+    /// Injests each of `words` as if by [`Self::update_and_round`].
+    #[inline]
+    fn update_words<const R: usize>(&mut self, words: &[u64]) {
+        #[cfg(all(
+            feature = "runtime-dispatch",
+            any(target_arch = "x86", target_arch = "x86_64")
+        ))]
+        if words.len() >= DISPATCH_MIN_WORDS {
+            // SAFETY: `[u8; 8]` has the same size as `u64` and a weaker alignment, and x86 is little-endian, so each chunk reads back as the same word
+            let chunks = unsafe { core::slice::from_raw_parts(words.as_ptr().cast(), words.len()) };
+            return sys::dispatch::update_chunks::<R>(&mut self.0, chunks);
+        }
+
+        for &word in words {
+            self.update_and_round::<R>(word);
+        }
+    }
+
     /// Consumes the state, and performs the full finalization step with R finalization rounds.
     /// Convience wrapper arround consuming the value (copying if necessary),
     ///  calling [`Self::update_before_final`], then R calls to [`Self::round`], then a call to [`Self::finish`]
@@ -106,6 +227,24 @@ impl SipHashState {
         }
         self.finish()
     }
+
+    /// Consumes the state, and performs the full SipHash-128 finalization step with R finalization rounds for each half of the result.
+    /// Convience wrapper arround calling [`Self::update_before_final128`], then R calls to [`Self::round`], then a call to [`Self::finish`] to produce the low word,
+    ///  followed by [`Self::update_before_final128_high`], then R calls to [`Self::round`], then a call to [`Self::finish`] to produce the high word.
+    pub fn update_and_final128<const R: usize>(mut self) -> u128 {
+        self.update_before_final128();
+        for _ in 0..R {
+            self.round();
+        }
+        let l = self.finish() as u128;
+        self.update_before_final128_high();
+        for _ in 0..R {
+            self.round();
+        }
+        let h = self.finish() as u128;
+
+        l | (h << 64)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -120,12 +259,15 @@ mod serde;
 /// This is more efficient than [`SipHasher`], especially when all written values are 8 bytes long,
 /// but produces different results from [`SipHasher`] when several smaller values are hashed, and may produce values that differ from other, similar operations, in surprising ways.
 /// As a notable example, hashing `[u32; 4]` would produce a different result than hashing the bytes of that same array (even on little-endian platforms).
+/// Bytes are read as little-endian words and integers are written by value, so the result is the same on every target.
 ///
 /// `C` and `D` are the parameters of SipHash-*C*-*D*. It is recommended that these values be small, but they can be arbitrary.
 ///
 /// Generally, C=2, and D=4 provides sufficient security for any use case, and C=1 and D=3 can produce a more efficient algorithm with lower security (though still sufficient for many use cases).
+///
+/// `B` is the [`SipBackend`] that implements the round function, which defaults to the implementation chosen for the target.
 #[derive(Copy, Clone, Debug)]
-pub struct RawSipHasher<const C: usize, const D: usize>(SipHashState);
+pub struct RawSipHasher<const C: usize, const D: usize, B = SipHashState>(B);
 
 impl<const C: usize, const D: usize> RawSipHasher<C, D> {
     /// Constructs a new [`RawSipHasher`]. This constructs the internal state as if by [`SipHashState::from_keys`]
@@ -133,27 +275,47 @@ impl<const C: usize, const D: usize> RawSipHasher<C, D> {
         Self(SipHashState::from_keys(k0, k1))
     }
 
+    /// Constructs a new [`RawSipHasher`] for use with [`RawSipHasher::finish128`]. This constructs the internal state as if by [`SipHashState::from_keys128`]
+    pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+        Self(SipHashState::from_keys128(k0, k1))
+    }
+}
+
+impl<const C: usize, const D: usize, B: SipBackend> RawSipHasher<C, D, B> {
+    /// Constructs a new [`RawSipHasher`] that uses the backend `B`. This constructs the internal state as if by [`SipBackend::from_keys`]
+    pub fn from_backend_keys(k0: u64, k1: u64) -> Self {
+        Self(B::from_keys(k0, k1))
+    }
+
+    /// Constructs a new [`RawSipHasher`] that uses the backend `B`, for use with [`RawSipHasher::finish128`].
+    /// This constructs the internal state as if by [`SipBackend::from_keys128`]
+    pub fn from_backend_keys128(k0: u64, k1: u64) -> Self {
+        Self(B::from_keys128(k0, k1))
+    }
+
     #[cfg(not(feature = "inspect-raw"))]
-    const fn from_state(state: SipHashState) -> Self {
+    #[allow(dead_code)] // used by the serde impls
+    const fn from_state(state: B) -> Self {
         Self(state)
     }
 
     /// Constructs a [`RawSipHasher`] that wraps a given internal state.
     #[cfg(feature = "inspect-raw")]
     #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
-    pub const fn from_state(state: SipHashState) -> Self {
+    pub const fn from_state(state: B) -> Self {
         Self(state)
     }
 
     #[cfg(not(feature = "inspect-raw"))]
-    const fn state(&self) -> &SipHashState {
+    #[allow(dead_code)] // used by the serde impls
+    const fn state(&self) -> &B {
         &self.0
     }
 
     /// Obtains the inner state for the purposes of debugging and serialization.
     #[cfg(feature = "inspect-raw")]
     #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
-    pub const fn state(&self) -> &SipHashState {
+    pub const fn state(&self) -> &B {
         &self.0
     }
 
@@ -164,23 +326,25 @@ impl<const C: usize, const D: usize> RawSipHasher<C, D> {
 
     /// Finishes the Hash by performing the finalization steps of a fresh copy of the state, before producing the final value of the hash
     pub fn finish(&self) -> u64 {
-        self.0.update_and_final::<D>().to_le()
+        self.0.update_and_final::<D>()
     }
 
-    /// Finishes the Hash by performing the finalization steps of a fresh copy of the state, before producing a 128-bit final value of the hash
+    /// Finishes the Hash by performing the SipHash-128 finalization steps of a fresh copy of the state, before producing a 128-bit final value of the hash.
+    ///
+    /// The hasher should be constructed with [`RawSipHasher::from_keys128`] when using this function.
     pub fn finish128(&self) -> u128 {
-        let l = self.0.update_and_final::<D>().to_le() as u128;
-        let h = self.0.update_and_final::<D>().to_le() as u128;
+        self.0.update_and_final128::<D>()
+    }
 
-        l | (h << 64)
+    /// Updates the hash with each of `words`, as if by [`RawSipHasher::update`].
+    pub fn update_words(&mut self, words: &[u64]) {
+        self.0.update_words::<C>(words)
     }
 
     /// Updates the hash using each 8 byte chunk of `bytes`, padding the remainder (if any) with 0 bytes.
     pub fn update_from_bytes(&mut self, bytes: &[u8]) {
         let (chunks, rem) = bytes.as_chunks::<8>();
-        for &chunk in chunks {
-            self.update(u64::from_le_bytes(chunk));
-        }
+        self.0.update_chunks::<C>(chunks);
 
         let mut v = [0x00; 8];
         v[..rem.len()].copy_from_slice(rem);
@@ -206,7 +370,7 @@ impl<const C: usize, const D: usize> RawSipHasher<C, D> {
     }
 }
 
-impl<const C: usize, const D: usize> Hasher for RawSipHasher<C, D> {
+impl<const C: usize, const D: usize, B: SipBackend> Hasher for RawSipHasher<C, D, B> {
     fn finish(&self) -> u64 {
         self.finish()
     }
@@ -270,10 +434,20 @@ impl<const C: usize, const D: usize> Hasher for RawSipHasher<C, D> {
     }
 }
 
-/// [`SipHasher`] is a complete implementation of SipHash, including
+/// [`SipHasher`] is a complete implementation of SipHash, including buffering of written bytes.
+///
+/// Written bytes are treated as a single message, so the result does not depend on how that message is split between calls to [`Hasher::write`].
+/// The message is read as a little-endian byte string, and the result of [`Hasher::finish`] is the reference 8 byte output read as a little-endian integer.
+/// Integers are written as their little-endian bytes, and `usize` and `isize` are widened to 64 bits, so the same value is produced on every target,
+///  for written bytes and for any [`Hash`][core::hash::Hash] impl alike.
+/// Use [`StdCompatSipHasher`] for the native-endian encoding of the standard library.
+///
+/// A hasher built by a [`BuildSipHasher`][crate::BuildSipHasher] can be returned to its keyed initial state with [`BuildSipHasher::reset`][crate::BuildSipHasher::reset].
+///
+/// `B` is the [`SipBackend`] that implements the round function, which defaults to the implementation chosen for the target.
 #[derive(Copy, Clone, Debug)]
-pub struct SipHasher<const C: usize, const D: usize> {
-    state: SipHashState,
+pub struct SipHasher<const C: usize, const D: usize, B = SipHashState> {
+    state: B,
     tail: u64,
     ntail: usize,
     bytes: usize,
@@ -282,8 +456,25 @@ pub struct SipHasher<const C: usize, const D: usize> {
 impl<const C: usize, const D: usize> SipHasher<C, D> {
     /// Constructs a new [`SipHasher`] from a default state using keys k0 and k1
     pub const fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self::from_initial_state(SipHashState::from_keys(k0, k1))
+    }
+
+    /// Constructs a new [`SipHasher`] from a default state using `key`
+    pub const fn from_key(key: SipKey) -> Self {
+        Self::new_with_keys(key.k0(), key.k1())
+    }
+}
+
+impl<const C: usize, const D: usize, B: SipBackend> SipHasher<C, D, B> {
+    /// Constructs a new [`SipHasher`] that uses the backend `B`, from a default state using keys k0 and k1
+    pub fn new_with_backend_keys(k0: u64, k1: u64) -> Self {
+        Self::from_initial_state(B::from_keys(k0, k1))
+    }
+
+    /// Constructs a new [`SipHasher`] that has not yet had any bytes written
+    pub(crate) const fn from_initial_state(state: B) -> Self {
         Self {
-            state: SipHashState::from_keys(k0, k1),
+            state,
             tail: 0u64,
             ntail: 0,
             bytes: 0,
@@ -295,18 +486,40 @@ impl<const C: usize, const D: usize> SipHasher<C, D> {
         self.state.update_and_round::<C>(word)
     }
 
-    /// Finishes the Hash by performing the finalization steps of a fresh copy of the state, before producing a 128-bit final value of the hash
+    /// Writes each of `words` as its little-endian bytes, which has the same result as passing those bytes to [`Hasher::write`].
+    ///
+    /// This is faster than writing the bytes when the data is already held as words.
+    /// Unlike [`Hasher::write_u64`], the result is the same on every target.
+    pub fn write_u64s(&mut self, words: &[u64]) {
+        self.bytes = self.bytes.wrapping_add(words.len().wrapping_mul(8));
+        if self.ntail == 0 {
+            self.state.update_words::<C>(words);
+            return;
+        }
+
+        // Each word completes the buffered word, and its remaining bytes become the new tail, so the number of buffered bytes is unchanged
+        let shift = (self.ntail << 3) as u32;
+        for &word in words {
+            self.update(self.tail | (word << shift));
+            self.tail = word >> (64 - shift);
+        }
+    }
+
+    /// Finishes the Hash by performing the finalization steps of a fresh copy of the state twice, before producing a 128-bit final value of the hash
+    ///
+    /// Both halves of the result are identical. Use [`SipHasher128`] to compute SipHash-128.
+    #[deprecated = "both halves of the result are identical, use `SipHasher128` for SipHash-128"]
     pub fn finish128(&self) -> u128 {
-        let l = self.state.update_and_final::<D>().to_le() as u128;
-        let h = self.state.update_and_final::<D>().to_le() as u128;
+        let l = self.state.update_and_final::<D>() as u128;
+        let h = self.state.update_and_final::<D>() as u128;
 
         l | (h << 64)
     }
 
-    /// Obtains the underlying raw [`SipHashState`]
+    /// Obtains the underlying raw [`SipHashState`] (or the state of the backend `B`)
     #[cfg(feature = "inspect-raw")]
     #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
-    pub const fn state(&self) -> &SipHashState {
+    pub const fn state(&self) -> &B {
         &self.state
     }
 
@@ -314,75 +527,457 @@ impl<const C: usize, const D: usize> SipHasher<C, D> {
     ///   and any words that have not yet been finished are discarded in a roundtrip through this function and [`SipHasher::state`].
     #[cfg(feature = "inspect-raw")]
     #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "inspect-raw")))]
-    pub const fn from_state(state: SipHashState) -> Self {
-        Self {
-            state,
-            tail: 0,
-            ntail: 0,
-            bytes: 0,
+    pub const fn from_state(state: B) -> Self {
+        Self::from_initial_state(state)
+    }
+
+    /// Produces a copy of the state that has injested the final word (the remaining tail bytes and the message length), ready for the finalization rounds.
+    fn final_state(&self) -> B {
+        let mut state = self.state;
+        state.update_and_round::<C>(self.tail | (((self.bytes as u64) & 0xFF) << 56));
+        state
+    }
+
+    /// Writes the little-endian bytes of an integer, with the same result as passing `bytes` to [`Hasher::write`].
+    /// `N` must be at most 8, so the bytes are zero extended to a word by a fixed size copy, rather than a call to `memcpy`.
+    ///
+    /// The first write to a new hasher, such as the integer key written by [`BuildHasher::hash_one`][core::hash::BuildHasher::hash_one], is not merged into a pending word,
+    ///  so that once inlined, hashing an integer key ingests it directly like [`BuildSipHasher::hash_u64`][crate::BuildSipHasher::hash_u64].
+    #[inline(always)]
+    fn write_le<const N: usize>(&mut self, bytes: [u8; N]) {
+        let mut word = [0u8; 8];
+        word[..N].copy_from_slice(&bytes);
+        let word = u64::from_le_bytes(word);
+        if self.ntail == 0 && self.bytes == 0 {
+            self.bytes = N;
+            if N == 8 {
+                self.update(word);
+            } else {
+                self.tail = word;
+                self.ntail = N;
+            }
+            return;
         }
+
+        self.write_partial_word(word, N);
+    }
+
+    /// Writes the `len` low bytes of `word` (read as little-endian), where the remaining bytes of `word` are 0.
+    /// `len` is between 1 and 8.
+    #[inline(always)]
+    fn write_partial_word(&mut self, word: u64, len: usize) {
+        self.bytes = self.bytes.wrapping_add(len);
+        let shift = self.ntail << 3;
+        let merged = self.tail | (word << shift);
+        let ntail = self.ntail + len;
+        if ntail < 8 {
+            self.tail = merged;
+            self.ntail = ntail;
+            return;
+        }
+
+        self.update(merged);
+        // The bytes of `word` that did not fit in the pending word. This is `word >> (64 - shift)`, split so that it is 0 rather than overflowing when `shift` is 0
+        self.tail = (word >> 1) >> (63 - shift);
+        self.ntail = ntail - 8;
     }
 }
 
-impl<const C: usize, const D: usize> Hasher for SipHasher<C, D> {
-    fn write(&mut self, mut s: &[u8]) {
-        self.bytes += s.len();
-        if self.ntail > 0 {
-            let required = s.len().min(8 - self.ntail);
-            let (l, r) = s.split_at(required);
+/// Reads up to 8 bytes as a little-endian word, padding the remainder with 0 bytes
+#[inline]
+fn load_partial(bytes: &[u8]) -> u64 {
+    let mut word = [0u8; 8];
+    word[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(word)
+}
 
-            (unsafe { core::mem::transmute::<&mut u64, &mut [u8; 8]>(&mut self.tail) })
-                [self.ntail..][..required]
-                .copy_from_slice(l);
+/// Calls `f` with each of `words` read as a little-endian word, in order.
+///
+/// On targets without cheap unaligned loads, long inputs are read by [`load_words_aligned`], rather than assembling each word from single byte loads.
+#[inline(always)]
+fn load_words(words: &[[u8; 8]], mut f: impl FnMut(u64)) {
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "wasm32"
+    )))]
+    if words.len() >= BULK_MIN_WORDS {
+        return load_words_aligned(words, f);
+    }
+
+    for &word in words {
+        f(u64::from_le_bytes(word));
+    }
+}
 
-            s = r;
-            if required + self.ntail == 8 {
-                self.update(self.tail.to_le());
-            } else {
-                return;
-            }
+/// Calls `f` with each of `words` read as a little-endian word, in order, using only aligned loads for all but the first and last word.
+///
+/// When `words` is not aligned to `u64`, each word is assembled from the two aligned words it overlaps with a pair of shifts.
+#[cfg_attr(
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "wasm32"
+    ),
+    allow(dead_code) // only used by the tests on these targets
+)]
+#[inline(always)]
+pub(crate) fn load_words_aligned(words: &[[u8; 8]], mut f: impl FnMut(u64)) {
+    // SAFETY: every bit pattern is a valid `u64`
+    let (head, aligned, _) = unsafe { words.as_flattened().align_to::<u64>() };
+    if head.is_empty() {
+        for &word in aligned {
+            f(u64::from_le(word));
         }
+        return;
+    }
 
-        let (chunks, remainder) = s.as_chunks::<8>();
+    let (Some(first), Some(last), Some((&start, aligned))) =
+        (words.first(), words.last(), aligned.split_first())
+    else {
+        for &word in words {
+            f(u64::from_le_bytes(word));
+        }
+        return;
+    };
+
+    // `words[0]` is `head` followed by the start of the first aligned word, and each following word is the end of one aligned word followed by the start of the next.
+    // The last word ends in the unaligned tail, so it is read directly like the first
+    let low = (head.len() * 8) as u32;
+    f(u64::from_le_bytes(*first));
+    let mut prev = u64::from_le(start);
+    for &next in aligned {
+        let next = u64::from_le(next);
+        f((prev >> (64 - low)) | (next << low));
+        prev = next;
+    }
+    f(u64::from_le_bytes(*last));
+}
 
-        for &chunk in chunks {
-            self.update(u64::from_ne_bytes(chunk));
+/// Appends `s` to a message whose pending (incomplete) word is the `ntail` low bytes of `tail`.
+///
+/// Each word that is completed is passed to `words`, in order, and the bytes after the last complete word become the new pending word.
+/// This is the buffering of [`SipHasher`] and [`MultiKeySipHasher`].
+#[inline(always)]
+pub(crate) fn write_buffered(
+    tail: &mut u64,
+    ntail: &mut usize,
+    mut s: &[u8],
+    mut words: impl FnMut(&[[u8; 8]]),
+) {
+    if *ntail > 0 {
+        let required = s.len().min(8 - *ntail);
+        let (l, r) = s.split_at(required);
+
+        *tail |= load_partial(l) << (*ntail << 3);
+        *ntail += required;
+        s = r;
+
+        if *ntail < 8 {
+            return;
         }
 
-        let mut tail = [0u8; 8];
-        tail[..remainder.len()].copy_from_slice(remainder);
-        self.ntail = remainder.len();
+        words(&[tail.to_le_bytes()]);
+    }
+
+    let (chunks, remainder) = s.as_chunks::<8>();
+
+    words(chunks);
+
+    *tail = load_partial(remainder);
+    *ntail = remainder.len();
+}
+
+impl<const C: usize, const D: usize, B: SipBackend> Hasher for SipHasher<C, D, B> {
+    fn write(&mut self, s: &[u8]) {
+        self.bytes = self.bytes.wrapping_add(s.len());
+        let state = &mut self.state;
+        write_buffered(&mut self.tail, &mut self.ntail, s, |words| {
+            state.update_chunks::<C>(words)
+        });
     }
 
     #[inline]
     fn finish(&self) -> u64 {
-        let mut state = *self;
-        if self.ntail > 0 {
-            let mut word = self.tail.to_le();
+        self.final_state().update_and_final::<D>()
+    }
 
-            if cfg!(target_endian = "big") {
-                word >>= (8 - self.ntail) << 3;
-            }
+    // The integer writes have the same result as writing their little-endian bytes, but merge the integer into the pending word directly
 
-            word &= (2u64 << ((self.ntail) << 3) - 1) - 1;
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write_le(i.to_le_bytes());
+    }
 
-            if self.ntail != 8 {
-                word |= ((self.bytes as u64) & 0xFF) << 56;
-            }
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write_le(i.to_le_bytes());
+    }
 
-            state.update(word);
-        } else {
-            state.update(((self.bytes as u64) & 0xFF) << 56);
-        }
-        state.state.update_and_final::<D>().to_le()
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write_le(i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write_le(i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        let bytes = i.to_le_bytes();
+        let (halves, _) = bytes.as_chunks::<8>();
+        self.write_le(halves[0]);
+        self.write_le(halves[1]);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
     }
 
     #[cfg(feature = "nightly-prefixfree_extras")]
     fn write_str(&mut self, s: &str) {
         self.write(s.as_bytes());
-        let word = self.tail.to_le() | (!0) << (self.ntail << 3);
+        let word = self.tail | (!0 << (self.ntail << 3));
         self.update(word);
         self.tail = 0;
         self.ntail = 0;
     }
 }
+
+/// [`SipHasher128`] is a complete implementation of SipHash-128, the 128-bit output variant of SipHash.
+///
+/// This buffers written bytes in the same way as [`SipHasher`], but uses the keyed initial state and finalization steps the specification defines for 128-bit output.
+/// The full result is obtained from [`SipHasher128::finish128`].
+///
+/// `C` and `D` are the parameters of SipHash-*C*-*D*.
+#[derive(Copy, Clone, Debug)]
+pub struct SipHasher128<const C: usize, const D: usize>(SipHasher<C, D>);
+
+impl<const C: usize, const D: usize> SipHasher128<C, D> {
+    /// Constructs a new [`SipHasher128`] from a default state using keys k0 and k1
+    pub const fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self(SipHasher::from_initial_state(SipHashState::from_keys128(
+            k0, k1,
+        )))
+    }
+
+    /// Constructs a new [`SipHasher128`] from a default state using `key`
+    pub const fn from_key(key: SipKey) -> Self {
+        Self::new_with_keys(key.k0(), key.k1())
+    }
+
+    /// Finishes the Hash by performing the SipHash-128 finalization steps of a fresh copy of the state, before producing the 128-bit final value of the hash.
+    ///
+    /// The result is the reference 16 byte output read as a little-endian integer (the low word is produced first).
+    pub fn finish128(&self) -> u128 {
+        self.0.final_state().update_and_final128::<D>()
+    }
+}
+
+impl<const C: usize, const D: usize> Hasher for SipHasher128<C, D> {
+    fn write(&mut self, s: &[u8]) {
+        self.0.write(s);
+    }
+
+    /// Returns the low 64 bits of [`SipHasher128::finish128`]
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128() as u64
+    }
+
+    // The integer writes use the encoding of [`SipHasher`], rather than the native-endian default
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.0.write_u8(i);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.0.write_u16(i);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.0.write_u32(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.0.write_u64(i);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.0.write_u128(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.0.write_usize(i);
+    }
+
+    #[cfg(feature = "nightly-prefixfree_extras")]
+    fn write_str(&mut self, s: &str) {
+        self.0.write_str(s);
+    }
+}
+
+/// Computes SipHash-`C`-`D` of `msg` with `key`.
+///
+/// This is the same as writing `msg` to [`SipHasher::from_key`] with a single call to [`Hasher::write`], and then calling [`Hasher::finish`].
+#[inline]
+pub fn siphash<const C: usize, const D: usize>(key: &SipKey, msg: &[u8]) -> u64 {
+    let mut hasher = SipHasher::<C, D>::from_key(*key);
+    hasher.write(msg);
+    hasher.finish()
+}
+
+/// Computes SipHash-128 (with `C` update rounds and `D` finalization rounds) of `msg` with `key`.
+///
+/// This is the same as writing `msg` to [`SipHasher128::from_key`] with a single call to [`Hasher::write`], and then calling [`SipHasher128::finish128`].
+#[inline]
+pub fn siphash128<const C: usize, const D: usize>(key: &SipKey, msg: &[u8]) -> u128 {
+    let mut hasher = SipHasher128::<C, D>::from_key(*key);
+    hasher.write(msg);
+    hasher.finish128()
+}
+
+/// [`StdCompatSipHasher`] is a [`SipHasher`] whose every [`Hasher`] method produces the same result as the SipHash implementation in the standard library.
+///
+/// With `C=1` and `D=3`, this matches `std::hash::DefaultHasher` (and the unstable `SipHasher13`) constructed with the same keys, and with `C=2` and `D=4`, this matches the deprecated [`core::hash::SipHasher`].
+/// This allows replacing those hashers without changing any persisted hash values.
+///
+/// In particular:
+/// * Integers are written as their native-endian bytes (so, like the standard library, results for integers depend on the endianness and pointer width of the target, unlike [`SipHasher`]),
+/// * Strings are written as their bytes followed by a single `0xFF` byte, and
+/// * Length prefixes are written as a `usize`.
+///
+/// This is a distinct type from [`SipHasher`] regardless of the enabled features, so enabling `nightly-prefixfree_extras` (which changes how [`SipHasher`] hashes strings) does not change the results of this type.
+///
+/// `C` and `D` are the parameters of SipHash-*C*-*D*.
+#[derive(Copy, Clone, Debug)]
+pub struct StdCompatSipHasher<const C: usize, const D: usize>(SipHasher<C, D>);
+
+impl<const C: usize, const D: usize> StdCompatSipHasher<C, D> {
+    /// Constructs a new [`StdCompatSipHasher`] using keys k0 and k1
+    pub const fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self(SipHasher::new_with_keys(k0, k1))
+    }
+
+    /// Constructs a new [`StdCompatSipHasher`] with both keys set to 0, as `std::hash::DefaultHasher::new` does
+    pub const fn new() -> Self {
+        Self::new_with_keys(0, 0)
+    }
+}
+
+impl<const C: usize, const D: usize> Default for StdCompatSipHasher<C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const C: usize, const D: usize> Hasher for StdCompatSipHasher<C, D> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write(&[i])
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_ne_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_ne_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_ne_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_ne_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write(&i.to_ne_bytes())
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8)
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16)
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128)
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_usize(i as usize)
+    }
+
+    #[cfg(feature = "nightly-prefixfree_extras")]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.write_usize(len);
+    }
+
+    #[cfg(feature = "nightly-prefixfree_extras")]
+    fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+        self.write_u8(0xFF);
+    }
+}
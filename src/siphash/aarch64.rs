@@ -0,0 +1,227 @@
+#[cfg(target_feature = "neon")]
+pub(super) mod neon {
+
+    use super::super::*;
+
+    use core::arch::aarch64::*;
+
+    use core::marker::PhantomData;
+
+    /// Implementation of the per-lane rotate used by the round function.
+    ///
+    /// The counts are right rotates to match the immediate of `XAR`, as a const argument such as `64 - N` cannot be computed from a const parameter.
+    pub trait Rotate {
+        /// Rotates the low lane of `v` right by `ROT1`, and the high lane by `ROT3`
+        fn rotate_lanes<const ROT1: i32, const ROT3: i32>(v: uint64x2_t) -> uint64x2_t;
+    }
+
+    /// Rotate using a pair of register shifts (`USHL`), where a negative count shifts right.
+    ///
+    /// The immediate rotate (`SHL` and `SRI`, as with `vsriq_n_u64`) applies the same count to both lanes, but `v1` and `v3` are rotated by different counts.
+    /// That would take two immediate rotates and a lane select per halfround, while `USHL` takes a count for each lane, so this needs only the two shifts and an `ORR`.
+    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(target_feature = "sha3", allow(dead_code))]
+    pub struct Shift;
+
+    /// Rotate using the SHA3 `XAR` instruction, which rotates both lanes by an immediate.
+    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(target_feature = "sha3"), allow(dead_code))]
+    pub struct Sha3;
+
+    impl Rotate for Shift {
+        #[inline(always)]
+        fn rotate_lanes<const ROT1: i32, const ROT3: i32>(v: uint64x2_t) -> uint64x2_t {
+            let left =
+                unsafe { vcombine_s64(vdup_n_s64(64 - ROT1 as i64), vdup_n_s64(64 - ROT3 as i64)) };
+            let right = unsafe { vsubq_s64(left, vdupq_n_s64(64)) };
+
+            unsafe { vorrq_u64(vshlq_u64(v, left), vshlq_u64(v, right)) }
+        }
+    }
+
+    #[cfg(target_feature = "sha3")]
+    impl Rotate for Sha3 {
+        // `XAR` applies the same count to both lanes, so rotate the whole vector by each lane's count and keep the matching lane of each
+        #[inline(always)]
+        fn rotate_lanes<const ROT1: i32, const ROT3: i32>(v: uint64x2_t) -> uint64x2_t {
+            let zero = unsafe { vdupq_n_u64(0) };
+            let lo = unsafe { vxarq_u64::<ROT1>(v, zero) };
+            let hi = unsafe { vxarq_u64::<ROT3>(v, zero) };
+
+            unsafe { vcopyq_laneq_u64::<1, 1>(lo, hi) }
+        }
+    }
+
+    #[cfg(target_feature = "sha3")]
+    pub type StaticRotate = Sha3;
+
+    #[cfg(not(target_feature = "sha3"))]
+    pub type StaticRotate = Shift;
+
+    /// SipHash state stored as `[v0, v2]` and `[v1, v3]` vectors, using `R` to perform rotates
+    #[derive(Copy, Clone, Debug)]
+    pub struct VectorState<R>(uint64x2_t, uint64x2_t, PhantomData<R>);
+
+    impl<R: Rotate> VectorState<R> {
+        #[inline]
+        pub const fn from_keys(k0: u64, k1: u64) -> Self {
+            Self::from_state([
+                k0 ^ SIPHASH_MAG1,
+                k1 ^ SIPHASH_MAG2,
+                k0 ^ SIPHASH_MAG3,
+                k1 ^ SIPHASH_MAG4,
+            ])
+        }
+
+        #[inline]
+        pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+            Self::from_state([
+                k0 ^ SIPHASH_MAG1,
+                k1 ^ SIPHASH_MAG2 ^ 0xee,
+                k0 ^ SIPHASH_MAG3,
+                k1 ^ SIPHASH_MAG4,
+            ])
+        }
+
+        #[inline]
+        pub const fn from_state([v0, v1, v2, v3]: [u64; 4]) -> Self {
+            unsafe {
+                Self(
+                    core::mem::transmute::<[u64; 2], uint64x2_t>([v0, v2]),
+                    core::mem::transmute::<[u64; 2], uint64x2_t>([v1, v3]),
+                    PhantomData,
+                )
+            }
+        }
+
+        #[inline]
+        pub const fn inspect_state(&self) -> [u64; 4] {
+            let [v0, v2] = unsafe { core::mem::transmute::<uint64x2_t, [u64; 2]>(self.0) };
+            let [v1, v3] = unsafe { core::mem::transmute::<uint64x2_t, [u64; 2]>(self.1) };
+
+            [v0, v1, v2, v3]
+        }
+
+        #[inline(always)]
+        pub fn update_before_rounds(&mut self, word: u64) {
+            let val = unsafe { vcombine_u64(vdup_n_u64(0), vdup_n_u64(word)) };
+            self.1 = unsafe { veorq_u64(self.1, val) };
+        }
+
+        #[inline(always)]
+        pub fn update_after_rounds(&mut self, word: u64) {
+            let val = unsafe { vcombine_u64(vdup_n_u64(word), vdup_n_u64(0)) };
+            self.0 = unsafe { veorq_u64(self.0, val) };
+        }
+
+        #[inline]
+        pub fn update_before_final(&mut self) {
+            let val = unsafe { vcombine_u64(vdup_n_u64(0), vdup_n_u64(0xff)) };
+            self.0 = unsafe { veorq_u64(self.0, val) };
+        }
+
+        #[inline]
+        pub fn update_before_final128(&mut self) {
+            let val = unsafe { vcombine_u64(vdup_n_u64(0), vdup_n_u64(0xee)) };
+            self.0 = unsafe { veorq_u64(self.0, val) };
+        }
+
+        #[inline]
+        pub fn update_before_final128_high(&mut self) {
+            let val = unsafe { vcombine_u64(vdup_n_u64(0xdd), vdup_n_u64(0)) };
+            self.1 = unsafe { veorq_u64(self.1, val) };
+        }
+
+        #[inline]
+        pub fn finish(self) -> u64 {
+            let s = unsafe { veorq_u64(self.0, self.1) };
+            unsafe { vgetq_lane_u64::<0>(s) ^ vgetq_lane_u64::<1>(s) }
+        }
+
+        #[inline(always)]
+        fn halfround<const ROT1: i32, const ROT3: i32>(
+            mut s0: uint64x2_t,
+            mut s1: uint64x2_t,
+        ) -> (uint64x2_t, uint64x2_t) {
+            // Compute one half of the round function, with [v0,v2] in s0, and [v1,v3] in s1, rotating [v1,v3] right by [ROT1,ROT3].
+            // See the x86 backend for the scalar operations this performs.
+            s0 = unsafe { vaddq_u64(s0, s1) };
+            s1 = R::rotate_lanes::<ROT1, ROT3>(s1);
+            s1 = unsafe { veorq_u64(s1, s0) };
+            // Rotate v0 by 32 (`REV64` on 32-bit elements rotates each lane by 32), then swap the lanes, keeping v2 from the unrotated vector.
+            // This sets up for the second halfround, or resets for the next full round
+            let rotated = unsafe { vreinterpretq_u64_u32(vrev64q_u32(vreinterpretq_u32_u64(s0))) };
+            s0 = unsafe { vextq_u64::<1>(s0, rotated) };
+
+            (s0, s1)
+        }
+
+        #[inline(always)]
+        pub fn round(&mut self) {
+            let Self(s0, s1, _) = *self;
+            // Right rotates by [51, 48] are left rotates by [rot1, rot3] = [13, 16]
+            let (s0, s1) = Self::halfround::<51, 48>(s0, s1);
+            // [rot1, rot3] = [17, 21]
+            let (s0, s1) = Self::halfround::<47, 43>(s0, s1);
+            *self = Self(s0, s1, PhantomData);
+        }
+    }
+}
+
+// The NEON round has not been measured against the scalar one on hardware, so the scalar state stays the default.
+// The vector state is available as [`backend::Neon`][super::backend::Neon].
+include!("generic.rs");
+
+#[cfg(all(test, target_feature = "neon"))]
+mod test {
+    use super::neon::*;
+    use crate::test::reference;
+
+    const K0: u64 = 0x0706050403020100;
+    const K1: u64 = 0x0f0e0d0c0b0a0908;
+
+    fn words() -> [u64; 37] {
+        core::array::from_fn(|i| (i as u64).wrapping_mul(0x9e3779b97f4a7c15))
+    }
+
+    /// SipHash-2-4 of [`words`], computed by the reference implementation
+    fn expected() -> u64 {
+        let mut out = [0u8; 8];
+        let msg = words().map(u64::to_le_bytes);
+        reference::siphash(2, 4, K0, K1, msg.as_flattened(), &mut out);
+        u64::from_le_bytes(out)
+    }
+
+    /// Finishes SipHash-2-4 from the state array after all of [`words`] has been injested
+    fn finish(state: [u64; 4]) -> u64 {
+        let mut state = crate::SipHashState::from_state(state);
+        state.update_and_round::<2>(((words().len() * 8) as u64) << 56);
+        state.update_and_final::<4>()
+    }
+
+    fn check_rotate<R: Rotate>() {
+        let mut state = VectorState::<R>::from_keys(K0, K1);
+        for word in words() {
+            state.update_before_rounds(word);
+            state.round();
+            state.round();
+            state.update_after_rounds(word);
+        }
+        assert_eq!(expected(), finish(state.inspect_state()));
+    }
+
+    // Miri does not implement the NEON shift and `XAR` intrinsics used by the rotates, so these tests need an AArch64 CPU (or emulator)
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn shift_round_matches_scalar() {
+        check_rotate::<Shift>();
+    }
+
+    #[test]
+    #[cfg(target_feature = "sha3")]
+    #[cfg_attr(miri, ignore)]
+    pub fn sha3_round_matches_scalar() {
+        check_rotate::<Sha3>();
+    }
+}
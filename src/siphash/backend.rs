@@ -0,0 +1,349 @@
+//! Named implementations of the SipHash round function, and the [`SipBackend`] trait that allows [`SipHasher`][super::SipHasher] and [`RawSipHasher`][super::RawSipHasher] to use them.
+//!
+//! [`SipHashState`] is the implementation chosen for the target (and used by default).
+//! The vector implementations are only available when the target features they require are enabled at compile time.
+
+use super::{load_words, SipHashState, SIPHASH_MAG1, SIPHASH_MAG2, SIPHASH_MAG3, SIPHASH_MAG4};
+
+/// An implementation of the SipHash state and round function.
+///
+/// Logically, the backend is the state array `[s0, s1, s2, s3]`, and each method has the same behaviour as the method of [`SipHashState`] with the same name.
+/// The provided methods are implemented in terms of the required methods, but implementations may override them with more efficient versions.
+pub trait SipBackend: Copy {
+    /// Constructs a new state from the state array.
+    fn from_state(state: [u64; 4]) -> Self;
+
+    /// Returns the current state array. [`SipBackend::from_state`] must be the exact inverse of this function.
+    fn inspect_state(&self) -> [u64; 4];
+
+    /// Performs the update operation to injest `word` before applying the update rounds to the state array.
+    /// This mutates the state by xoring `word` into s3.
+    fn update_before_rounds(&mut self, word: u64);
+
+    /// Performs the update operation to injest `word` after applying the update rounds to the state array.
+    /// This mutates the state by xoring `word` into s0.
+    fn update_after_rounds(&mut self, word: u64);
+
+    /// Performs the update operation before the finalization rounds.
+    /// This mutates the state by xoring `0xff` into s2.
+    fn update_before_final(&mut self);
+
+    /// Consumes the state and xors each word of the state array together.
+    fn finish(self) -> u64;
+
+    /// Performs a single SipHash round operation on the state array.
+    fn round(&mut self);
+
+    /// Constructs a new state from the specified keys, as by [`SipHashState::from_keys`].
+    #[inline]
+    fn from_keys(k0: u64, k1: u64) -> Self {
+        Self::from_state([
+            k0 ^ SIPHASH_MAG1,
+            k1 ^ SIPHASH_MAG2,
+            k0 ^ SIPHASH_MAG3,
+            k1 ^ SIPHASH_MAG4,
+        ])
+    }
+
+    /// Constructs a new state for SipHash-128 from the specified keys, as by [`SipHashState::from_keys128`].
+    #[inline]
+    fn from_keys128(k0: u64, k1: u64) -> Self {
+        Self::from_state([
+            k0 ^ SIPHASH_MAG1,
+            k1 ^ SIPHASH_MAG2 ^ 0xee,
+            k0 ^ SIPHASH_MAG3,
+            k1 ^ SIPHASH_MAG4,
+        ])
+    }
+
+    /// Performs the update operation before the first set of finalization rounds of SipHash-128.
+    /// This mutates the state by xoring `0xee` into s2.
+    #[inline]
+    fn update_before_final128(&mut self) {
+        let mut state = self.inspect_state();
+        state[2] ^= 0xee;
+        *self = Self::from_state(state);
+    }
+
+    /// Performs the update operation before the second set of finalization rounds of SipHash-128.
+    /// This mutates the state by xoring `0xdd` into s1.
+    #[inline]
+    fn update_before_final128_high(&mut self) {
+        let mut state = self.inspect_state();
+        state[1] ^= 0xdd;
+        *self = Self::from_state(state);
+    }
+
+    /// Injests `val` and then performs `R` siphash rounds, as by [`SipHashState::update_and_round`].
+    #[inline]
+    fn update_and_round<const R: usize>(&mut self, val: u64) {
+        self.update_before_rounds(val);
+        for _ in 0..R {
+            self.round();
+        }
+        self.update_after_rounds(val);
+    }
+
+    /// Injests each of `words`, read as little-endian, as if by [`SipBackend::update_and_round`].
+    #[inline]
+    fn update_chunks<const R: usize>(&mut self, words: &[[u8; 8]]) {
+        load_words(words, |word| self.update_and_round::<R>(word));
+    }
+
+    /// Injests each of `words` as if by [`SipBackend::update_and_round`].
+    #[inline]
+    fn update_words<const R: usize>(&mut self, words: &[u64]) {
+        for &word in words {
+            self.update_and_round::<R>(word);
+        }
+    }
+
+    /// Consumes the state, and performs the full finalization step with R finalization rounds, as by [`SipHashState::update_and_final`].
+    #[inline]
+    fn update_and_final<const R: usize>(mut self) -> u64 {
+        self.update_before_final();
+        for _ in 0..R {
+            self.round();
+        }
+        self.finish()
+    }
+
+    /// Consumes the state, and performs the full SipHash-128 finalization step with R finalization rounds for each half of the result,
+    ///  as by [`SipHashState::update_and_final128`].
+    #[inline]
+    fn update_and_final128<const R: usize>(mut self) -> u128 {
+        self.update_before_final128();
+        for _ in 0..R {
+            self.round();
+        }
+        let l = self.finish() as u128;
+        self.update_before_final128_high();
+        for _ in 0..R {
+            self.round();
+        }
+        let h = self.finish() as u128;
+
+        l | (h << 64)
+    }
+}
+
+impl SipBackend for SipHashState {
+    #[inline]
+    fn from_state(state: [u64; 4]) -> Self {
+        SipHashState::from_state(state)
+    }
+
+    #[inline]
+    fn inspect_state(&self) -> [u64; 4] {
+        SipHashState::inspect_state(self)
+    }
+
+    #[inline]
+    fn update_before_rounds(&mut self, word: u64) {
+        SipHashState::update_before_rounds(self, word)
+    }
+
+    #[inline]
+    fn update_after_rounds(&mut self, word: u64) {
+        SipHashState::update_after_rounds(self, word)
+    }
+
+    #[inline]
+    fn update_before_final(&mut self) {
+        SipHashState::update_before_final(self)
+    }
+
+    #[inline]
+    fn finish(self) -> u64 {
+        SipHashState::finish(self)
+    }
+
+    #[inline]
+    fn round(&mut self) {
+        SipHashState::round(self)
+    }
+
+    #[inline]
+    fn from_keys(k0: u64, k1: u64) -> Self {
+        SipHashState::from_keys(k0, k1)
+    }
+
+    #[inline]
+    fn from_keys128(k0: u64, k1: u64) -> Self {
+        SipHashState::from_keys128(k0, k1)
+    }
+
+    #[inline]
+    fn update_before_final128(&mut self) {
+        SipHashState::update_before_final128(self)
+    }
+
+    #[inline]
+    fn update_before_final128_high(&mut self) {
+        SipHashState::update_before_final128_high(self)
+    }
+
+    #[inline]
+    fn update_chunks<const R: usize>(&mut self, words: &[[u8; 8]]) {
+        SipHashState::update_chunks::<R>(self, words)
+    }
+
+    #[inline]
+    fn update_words<const R: usize>(&mut self, words: &[u64]) {
+        SipHashState::update_words::<R>(self, words)
+    }
+}
+
+/// Implements [`SipBackend`] for a newtype by forwarding to the inherent methods of the wrapped state
+macro_rules! forward_backend {
+    ($ty:ty, $inner:ty) => {
+        impl SipBackend for $ty {
+            #[inline]
+            fn from_state(state: [u64; 4]) -> Self {
+                Self(<$inner>::from_state(state))
+            }
+
+            #[inline]
+            fn inspect_state(&self) -> [u64; 4] {
+                self.0.inspect_state()
+            }
+
+            #[inline]
+            fn update_before_rounds(&mut self, word: u64) {
+                self.0.update_before_rounds(word)
+            }
+
+            #[inline]
+            fn update_after_rounds(&mut self, word: u64) {
+                self.0.update_after_rounds(word)
+            }
+
+            #[inline]
+            fn update_before_final(&mut self) {
+                self.0.update_before_final()
+            }
+
+            #[inline]
+            fn finish(self) -> u64 {
+                self.0.finish()
+            }
+
+            #[inline]
+            fn round(&mut self) {
+                self.0.round()
+            }
+
+            #[inline]
+            fn from_keys(k0: u64, k1: u64) -> Self {
+                Self(<$inner>::from_keys(k0, k1))
+            }
+
+            #[inline]
+            fn from_keys128(k0: u64, k1: u64) -> Self {
+                Self(<$inner>::from_keys128(k0, k1))
+            }
+
+            #[inline]
+            fn update_before_final128(&mut self) {
+                self.0.update_before_final128()
+            }
+
+            #[inline]
+            fn update_before_final128_high(&mut self) {
+                self.0.update_before_final128_high()
+            }
+        }
+    };
+}
+
+mod generic {
+    include!("generic.rs");
+}
+
+/// The portable scalar implementation, which stores the state as four `u64` values.
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Generic(generic::SipHashState);
+
+forward_backend!(Generic, generic::SipHashState);
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+use super::sys::sse;
+
+/// The x86 vector implementation using only SSE2 instructions, which stores the state as `[s0, s2]` and `[s1, s3]` vectors.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Sse(sse::VectorState<sse::Sse2>);
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+forward_backend!(Sse, sse::VectorState<sse::Sse2>);
+
+/// The x86 vector implementation using the AVX2 variable shifts for rotates. Requires the `avx2` target feature.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+))]
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Avx2(sse::VectorState<sse::Avx2>);
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+))]
+forward_backend!(Avx2, sse::VectorState<sse::Avx2>);
+
+/// The x86 vector implementation using the AVX-512 variable rotate. Requires the `avx512f` and `avx512vl` target features.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx512f",
+    target_feature = "avx512vl"
+))]
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Avx512(sse::VectorState<sse::Avx512>);
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx512f",
+    target_feature = "avx512vl"
+))]
+forward_backend!(Avx512, sse::VectorState<sse::Avx512>);
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use super::sys::neon;
+
+/// The AArch64 vector implementation, which stores the state as `[s0, s2]` and `[s1, s3]` vectors. Requires the `neon` target feature.
+///
+/// Rotates use the SHA3 `XAR` instruction if the `sha3` target feature is enabled, and register shifts otherwise.
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Neon(neon::VectorState<neon::StaticRotate>);
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+forward_backend!(Neon, neon::VectorState<neon::StaticRotate>);
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use super::sys::simd128;
+
+/// The WebAssembly vector implementation, which stores the state as `[s0, s2]` and `[s1, s3]` vectors. Requires the `simd128` target feature.
+///
+/// This is slower than [`Generic`] in current engines, and is provided for comparison.
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Simd128(simd128::VectorState);
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+forward_backend!(Simd128, simd128::VectorState);
@@ -36,6 +36,14 @@ impl SipHashState {
         Self(s0, s1)
     }
 
+    #[inline]
+    pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+        let s0 = unsafe { core::mem::transmute([k0 ^ SIPHASH_MAG1, k0 ^ SIPHASH_MAG3]) };
+        let s1 = unsafe { core::mem::transmute([k1 ^ SIPHASH_MAG2 ^ 0xee, k1 ^ SIPHASH_MAG4]) };
+
+        Self(s0, s1)
+    }
+
     #[inline]
     pub const fn from_state([v0, v1, v2, v3]: [u64; 4]) -> Self {
         unsafe {
@@ -49,7 +57,7 @@ impl SipHashState {
     #[inline]
     pub const fn inspect_state(&self) -> [u64; 4] {
         let [v0, v2] = unsafe { core::mem::transmute(self.0) };
-        let [v1, v3] = unsafe { core::mem::transmute(self.0) };
+        let [v1, v3] = unsafe { core::mem::transmute(self.1) };
 
         [v0, v1, v2, v3]
     }
@@ -69,6 +77,16 @@ impl SipHashState {
         *hi_u64(&mut self.0) ^= 0xff;
     }
 
+    #[inline]
+    pub fn update_before_final128(&mut self) {
+        *hi_u64(&mut self.0) ^= 0xee;
+    }
+
+    #[inline]
+    pub fn update_before_final128_high(&mut self) {
+        *lo_u64(&mut self.1) ^= 0xdd;
+    }
+
     #[inline]
     pub fn finish(&self) -> u64 {
         let s0 = self.0;
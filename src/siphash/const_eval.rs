@@ -0,0 +1,126 @@
+use super::*;
+
+/// Performs a single SipHash round on the state array `[v0, v1, v2, v3]`
+const fn round([mut v0, mut v1, mut v2, mut v3]: [u64; 4]) -> [u64; 4] {
+    v0 = v0.wrapping_add(v1);
+    v2 = v2.wrapping_add(v3);
+    v1 = v1.rotate_left(13) ^ v0;
+    v3 = v3.rotate_left(16) ^ v2;
+    v0 = v0.rotate_left(32);
+
+    v2 = v2.wrapping_add(v1);
+    v0 = v0.wrapping_add(v3);
+    v1 = v1.rotate_left(17) ^ v2;
+    v3 = v3.rotate_left(21) ^ v0;
+    v2 = v2.rotate_left(32);
+
+    [v0, v1, v2, v3]
+}
+
+/// Performs `R` rounds on the state array
+const fn rounds<const R: usize>(mut v: [u64; 4]) -> [u64; 4] {
+    let mut i = 0;
+    while i < R {
+        v = round(v);
+        i += 1;
+    }
+    v
+}
+
+/// Injests `word` and performs `R` rounds, as by [`SipHashState::update_and_round`]
+const fn update_and_round<const R: usize>(mut v: [u64; 4], word: u64) -> [u64; 4] {
+    v[3] ^= word;
+    v = rounds::<R>(v);
+    v[0] ^= word;
+    v
+}
+
+/// Injests all of `msg`, including the final word with the message length, as [`SipHasher`] does
+const fn update_message<const C: usize>(mut v: [u64; 4], msg: &[u8]) -> [u64; 4] {
+    let mut rest = msg;
+    while let Some((word, tail)) = rest.split_first_chunk::<8>() {
+        v = update_and_round::<C>(v, u64::from_le_bytes(*word));
+        rest = tail;
+    }
+
+    let mut last = ((msg.len() as u64) & 0xFF) << 56;
+    let mut i = 0;
+    while i < rest.len() {
+        last |= (rest[i] as u64) << (i * 8);
+        i += 1;
+    }
+    update_and_round::<C>(v, last)
+}
+
+/// Xors each word of the state array together
+const fn finish([v0, v1, v2, v3]: [u64; 4]) -> u64 {
+    v0 ^ v1 ^ v2 ^ v3
+}
+
+/// Computes SipHash-`C`-`D` of `msg` with the keys `k0` and `k1`, in a `const fn`.
+///
+/// The result is the same as writing `msg` to a [`SipHasher`] constructed by [`SipHasher::new_with_keys`], and then calling [`Hasher::finish`].
+/// This uses a plain scalar implementation of the round function, so it is intended for computing hashes at compile time,
+///  for example to build tables in `const` items. At runtime, [`SipHasher`] is faster.
+pub const fn const_hash<const C: usize, const D: usize>(k0: u64, k1: u64, msg: &[u8]) -> u64 {
+    let mut v = update_message::<C>(SipHashState::from_keys(k0, k1).inspect_state(), msg);
+    v[2] ^= 0xff;
+    finish(rounds::<D>(v))
+}
+
+/// Computes SipHash-128 (with `C` update rounds and `D` finalization rounds) of `msg` with the keys `k0` and `k1`, in a `const fn`.
+///
+/// The result is the same as writing `msg` to a [`SipHasher128`] constructed by [`SipHasher128::new_with_keys`], and then calling [`SipHasher128::finish128`].
+/// Like [`const_hash`], this is intended for computing hashes at compile time.
+pub const fn const_hash128<const C: usize, const D: usize>(k0: u64, k1: u64, msg: &[u8]) -> u128 {
+    let mut v = update_message::<C>(SipHashState::from_keys128(k0, k1).inspect_state(), msg);
+    v[2] ^= 0xee;
+    v = rounds::<D>(v);
+    let l = finish(v) as u128;
+    v[1] ^= 0xdd;
+    v = rounds::<D>(v);
+    let h = finish(v) as u128;
+
+    l | (h << 64)
+}
+
+/// Converts a string, byte string, or byte slice to the bytes hashed by [`siphash!`][crate::siphash!] and [`sip_phf_map!`][crate::sip_phf_map!], in a `const` context.
+#[doc(hidden)]
+pub struct KeyBytes<T>(pub T);
+
+impl<'a> KeyBytes<&'a str> {
+    #[doc(hidden)]
+    pub const fn get(self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> KeyBytes<&'a [u8]> {
+    #[doc(hidden)]
+    pub const fn get(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a, const N: usize> KeyBytes<&'a [u8; N]> {
+    #[doc(hidden)]
+    pub const fn get(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+/// Computes SipHash-`C`-`D` of a string, byte string, or byte slice at compile time, as by [`const_hash`].
+///
+/// The syntax is `siphash!(C, D; k0, k1, msg)`. All of the arguments must be constant expressions, and the result is evaluated in a `const` block.
+#[macro_export]
+macro_rules! siphash {
+    ($c:expr, $d:expr; $k0:expr, $k1:expr, $msg:expr $(,)?) => {
+        const {
+            $crate::siphash::const_hash::<{ $c }, { $d }>(
+                $k0,
+                $k1,
+                $crate::siphash::KeyBytes($msg).get(),
+            )
+        }
+    };
+}
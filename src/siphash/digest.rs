@@ -0,0 +1,55 @@
+use digest::common::KeySizeUser;
+use digest::consts::{U16, U8};
+use digest::{
+    FixedOutput, FixedOutputReset, Key, KeyInit, MacMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+use super::{SipKey, SipMac, SipMac128};
+
+macro_rules! impl_digest_mac {
+    ($mac:ident, $output_size:ty) => {
+        impl<const C: usize, const D: usize> MacMarker for $mac<C, D> {}
+
+        impl<const C: usize, const D: usize> KeySizeUser for $mac<C, D> {
+            type KeySize = U16;
+        }
+
+        impl<const C: usize, const D: usize> KeyInit for $mac<C, D> {
+            fn new(key: &Key<Self>) -> Self {
+                Self::new(&SipKey::from_bytes((*key).into()))
+            }
+        }
+
+        impl<const C: usize, const D: usize> OutputSizeUser for $mac<C, D> {
+            type OutputSize = $output_size;
+        }
+
+        impl<const C: usize, const D: usize> Update for $mac<C, D> {
+            fn update(&mut self, data: &[u8]) {
+                self.update(data);
+            }
+        }
+
+        impl<const C: usize, const D: usize> FixedOutput for $mac<C, D> {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finalize());
+            }
+        }
+
+        impl<const C: usize, const D: usize> Reset for $mac<C, D> {
+            fn reset(&mut self) {
+                self.reset();
+            }
+        }
+
+        impl<const C: usize, const D: usize> FixedOutputReset for $mac<C, D> {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.clone().finalize());
+                self.reset();
+            }
+        }
+    };
+}
+
+impl_digest_mac!(SipMac, U8);
+impl_digest_mac!(SipMac128, U16);
@@ -14,6 +14,16 @@ impl SipHashState {
         )
     }
 
+    #[inline]
+    pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+        Self(
+            k0 ^ SIPHASH_MAG1,
+            k0 ^ SIPHASH_MAG3,
+            k1 ^ SIPHASH_MAG2 ^ 0xee,
+            k1 ^ SIPHASH_MAG4,
+        )
+    }
+
     #[inline]
     pub const fn from_state([v0, v1, v2, v3]: [u64; 4]) -> Self {
         Self(v0, v2, v1, v3)
@@ -64,6 +74,16 @@ impl SipHashState {
         self.1 ^= 0xff;
     }
 
+    #[inline]
+    pub fn update_before_final128(&mut self) {
+        self.1 ^= 0xee;
+    }
+
+    #[inline]
+    pub fn update_before_final128_high(&mut self) {
+        self.2 ^= 0xdd;
+    }
+
     #[inline]
     pub fn finish(self) -> u64 {
         self.0 ^ self.1 ^ self.2 ^ self.3
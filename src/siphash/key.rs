@@ -0,0 +1,148 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// A 128-bit SipHash key, made up of the two words `k0` and `k1`.
+///
+/// The byte representation used by [`SipKey::from_bytes`], [`SipKey::to_bytes`], and the hex format is the one used by the reference implementation:
+///  `k0` is the first 8 bytes read as a little-endian integer, and `k1` is the last 8 bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SipKey {
+    k0: u64,
+    k1: u64,
+}
+
+impl SipKey {
+    /// Constructs a new [`SipKey`] from the words `k0` and `k1`
+    pub const fn new(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+
+    /// Constructs a new [`SipKey`] from the 16 bytes of the reference key layout
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        let (words, _) = bytes.as_chunks::<8>();
+        Self::new(u64::from_le_bytes(words[0]), u64::from_le_bytes(words[1]))
+    }
+
+    /// Returns the 16 bytes of the reference key layout
+    pub const fn to_bytes(self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        let (k0, k1) = bytes.split_at_mut(8);
+        k0.copy_from_slice(&self.k0.to_le_bytes());
+        k1.copy_from_slice(&self.k1.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the first word of the key
+    pub const fn k0(self) -> u64 {
+        self.k0
+    }
+
+    /// Returns the second word of the key
+    pub const fn k1(self) -> u64 {
+        self.k1
+    }
+
+    /// Parses a key from 32 hex digits (in either case), which are the 16 bytes of the reference key layout in order.
+    ///
+    /// This is a `const fn`, so fixed keys can be written as hex in `const` items.
+    pub const fn from_hex(hex: &str) -> Result<Self, ParseSipKeyError> {
+        let digits = hex.as_bytes();
+        if digits.len() != 32 {
+            return Err(ParseSipKeyError::InvalidLength(digits.len()));
+        }
+
+        let mut bytes = [0u8; 16];
+        let mut i = 0;
+        while i < 32 {
+            let digit = match digits[i] {
+                c @ b'0'..=b'9' => c - b'0',
+                c @ b'a'..=b'f' => c - b'a' + 10,
+                c @ b'A'..=b'F' => c - b'A' + 10,
+                _ => return Err(ParseSipKeyError::InvalidDigit(i)),
+            };
+            bytes[i / 2] |= digit << (4 * (1 - i % 2));
+            i += 1;
+        }
+
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Generates a new random key using the random number generator provided by the operating system
+    #[cfg(any(doc, feature = "random-state"))]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(feature = "random-state")))]
+    pub fn from_system_rng() -> Self {
+        let mut bytes = [0u8; 16];
+
+        getrandom::fill(&mut bytes).unwrap();
+
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<[u8; 16]> for SipKey {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<SipKey> for [u8; 16] {
+    fn from(key: SipKey) -> Self {
+        key.to_bytes()
+    }
+}
+
+impl FromStr for SipKey {
+    type Err = ParseSipKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+/// Formats the key as 32 lowercase hex digits, which can be parsed by [`SipKey::from_hex`]
+impl fmt::LowerHex for SipKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.to_bytes() {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the key as 32 uppercase hex digits, which can be parsed by [`SipKey::from_hex`]
+impl fmt::UpperHex for SipKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.to_bytes() {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the key as 32 lowercase hex digits, as by [`LowerHex`][fmt::LowerHex]
+impl fmt::Display for SipKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+/// The error returned when parsing a [`SipKey`] from hex fails
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseSipKeyError {
+    /// The input did not have exactly 32 digits. Contains the length of the input.
+    InvalidLength(usize),
+    /// The input contained a byte that is not a hex digit. Contains the index of the byte.
+    InvalidDigit(usize),
+}
+
+impl fmt::Display for ParseSipKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "expected 32 hex digits, found {len} bytes"),
+            Self::InvalidDigit(index) => write!(f, "invalid hex digit at index {index}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseSipKeyError {}
@@ -0,0 +1,134 @@
+use core::fmt;
+use core::hash::Hasher;
+
+use super::{SipHasher, SipHasher128, SipKey};
+
+/// [`SipMac`] computes and verifies 64-bit SipHash-*C*-*D* message authentication tags.
+///
+/// Messages are fed through the same streaming core as [`SipHasher`], so the tag of a message does not depend on how it is split between calls to [`SipMac::update`].
+/// Tags are the reference output bytes of SipHash (the result as a little-endian integer).
+///
+/// Tags should be checked with [`SipMac::verify`], which compares in constant time, rather than by comparing the result of [`SipMac::finalize`] with `==`.
+///
+/// The [`Debug`][fmt::Debug] output only names the type, so that the key and the state derived from it are not printed.
+#[derive(Clone)]
+pub struct SipMac<const C: usize, const D: usize> {
+    key: SipKey,
+    hasher: SipHasher<C, D>,
+}
+
+impl<const C: usize, const D: usize> SipMac<C, D> {
+    /// Constructs a new [`SipMac`] that authenticates messages with `key`
+    pub const fn new(key: &SipKey) -> Self {
+        Self {
+            key: *key,
+            hasher: SipHasher::from_key(*key),
+        }
+    }
+
+    /// Appends `data` to the message
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.write(data);
+    }
+
+    /// Discards the message written so far, so that the [`SipMac`] can authenticate a new message with the same key
+    #[inline]
+    pub fn reset(&mut self) {
+        self.hasher = SipHasher::from_key(self.key);
+    }
+
+    /// Returns the tag of the message
+    #[inline]
+    pub fn finalize(self) -> [u8; 8] {
+        self.hasher.finish().to_le_bytes()
+    }
+
+    /// Checks that `tag` is the tag of the message.
+    ///
+    /// The comparison takes the same time regardless of which bytes of `tag` differ from the computed tag.
+    pub fn verify(self, tag: &[u8; 8]) -> Result<(), MacError> {
+        let diff = u64::from_le_bytes(self.finalize()) ^ u64::from_le_bytes(*tag);
+        check_diff(diff as u128)
+    }
+}
+
+/// [`SipMac128`] computes and verifies 128-bit SipHash-*C*-*D* message authentication tags, using the SipHash-128 finalization.
+///
+/// This is otherwise the same as [`SipMac`]. Tags are the reference 16 output bytes of SipHash-128, as produced by [`SipHasher128::finish128`] in little-endian order.
+#[derive(Clone)]
+pub struct SipMac128<const C: usize, const D: usize> {
+    key: SipKey,
+    hasher: SipHasher128<C, D>,
+}
+
+impl<const C: usize, const D: usize> SipMac128<C, D> {
+    /// Constructs a new [`SipMac128`] that authenticates messages with `key`
+    pub const fn new(key: &SipKey) -> Self {
+        Self {
+            key: *key,
+            hasher: SipHasher128::from_key(*key),
+        }
+    }
+
+    /// Appends `data` to the message
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.write(data);
+    }
+
+    /// Discards the message written so far, as by [`SipMac::reset`]
+    #[inline]
+    pub fn reset(&mut self) {
+        self.hasher = SipHasher128::from_key(self.key);
+    }
+
+    /// Returns the tag of the message
+    #[inline]
+    pub fn finalize(self) -> [u8; 16] {
+        self.hasher.finish128().to_le_bytes()
+    }
+
+    /// Checks that `tag` is the tag of the message, in constant time as by [`SipMac::verify`].
+    pub fn verify(self, tag: &[u8; 16]) -> Result<(), MacError> {
+        let diff = u128::from_le_bytes(self.finalize()) ^ u128::from_le_bytes(*tag);
+        check_diff(diff)
+    }
+}
+
+impl<const C: usize, const D: usize> fmt::Debug for SipMac<C, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SipMac").finish_non_exhaustive()
+    }
+}
+
+impl<const C: usize, const D: usize> fmt::Debug for SipMac128<C, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SipMac128").finish_non_exhaustive()
+    }
+}
+
+/// Reports whether the xor of the computed and expected tags is zero.
+///
+/// The tags are compared as whole integers, so there is no early exit at the first differing byte,
+///  and [`black_box`][core::hint::black_box] keeps the compiler from turning the single comparison back into a bytewise one.
+#[inline(never)]
+fn check_diff(diff: u128) -> Result<(), MacError> {
+    if core::hint::black_box(diff) == 0 {
+        Ok(())
+    } else {
+        Err(MacError)
+    }
+}
+
+/// The error returned by [`SipMac::verify`] and [`SipMac128::verify`] when the tag does not match the message
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MacError;
+
+impl fmt::Display for MacError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MAC tag mismatch")
+    }
+}
+
+impl core::error::Error for MacError {}
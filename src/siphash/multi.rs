@@ -0,0 +1,390 @@
+use super::*;
+
+/// Raw state of `N` independent SipHash instances, which are updated in lockstep.
+///
+/// Each word of the state array is stored for all `N` instances together, so every operation of the round function applies to all of the instances at once.
+/// This allows the compiler to vectorize the round across the instances (using 4 lanes with AVX2, or 8 with AVX-512),
+///  which is much more parallel than vectorizing a single [`SipHashState`].
+///
+/// Each instance (or lane) behaves exactly like a [`SipHashState`] that is given the same operations.
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct SipHashStateX<const N: usize> {
+    v0: [u64; N],
+    v1: [u64; N],
+    v2: [u64; N],
+    v3: [u64; N],
+}
+
+/// A [`SipHashStateX`] with 4 lanes, which fills an AVX2 register
+pub type SipHashStateX4 = SipHashStateX<4>;
+
+/// A [`SipHashStateX`] with 8 lanes, which fills an AVX-512 register
+pub type SipHashStateX8 = SipHashStateX<8>;
+
+impl<const N: usize> SipHashStateX<N> {
+    /// Constructs a new [`SipHashStateX`], where each lane is initialized as if by [`SipHashState::from_keys`] with the corresponding elements of `k0` and `k1`
+    #[inline]
+    pub fn from_keys(k0: [u64; N], k1: [u64; N]) -> Self {
+        Self {
+            v0: k0.map(|k| k ^ SIPHASH_MAG1),
+            v1: k1.map(|k| k ^ SIPHASH_MAG2),
+            v2: k0.map(|k| k ^ SIPHASH_MAG3),
+            v3: k1.map(|k| k ^ SIPHASH_MAG4),
+        }
+    }
+
+    /// Constructs a new [`SipHashStateX`] for SipHash-128, where each lane is initialized as if by [`SipHashState::from_keys128`]
+    #[inline]
+    pub fn from_keys128(k0: [u64; N], k1: [u64; N]) -> Self {
+        let mut state = Self::from_keys(k0, k1);
+        for v1 in &mut state.v1 {
+            *v1 ^= 0xee;
+        }
+        state
+    }
+
+    /// Constructs a new [`SipHashStateX`] from the state array of each lane
+    #[inline]
+    pub fn from_states(states: [[u64; 4]; N]) -> Self {
+        Self {
+            v0: core::array::from_fn(|i| states[i][0]),
+            v1: core::array::from_fn(|i| states[i][1]),
+            v2: core::array::from_fn(|i| states[i][2]),
+            v3: core::array::from_fn(|i| states[i][3]),
+        }
+    }
+
+    /// Returns the current state array of each lane. This is the exact inverse of [`SipHashStateX::from_states`]
+    #[inline]
+    pub fn inspect_states(&self) -> [[u64; 4]; N] {
+        core::array::from_fn(|i| [self.v0[i], self.v1[i], self.v2[i], self.v3[i]])
+    }
+
+    /// Constructs a new [`SipHashStateX`] where every lane starts from `state`
+    #[inline]
+    pub fn splat(state: SipHashState) -> Self {
+        Self::from_states([state.inspect_state(); N])
+    }
+
+    /// Returns the state of lane `n`
+    ///
+    /// ## Panics
+    /// Panics if `n` is not less than `N`
+    #[inline]
+    pub fn lane(&self, n: usize) -> SipHashState {
+        SipHashState::from_state(self.lane_state(n))
+    }
+
+    /// Performs [`SipHashState::update_before_rounds`] on each lane with the corresponding element of `words`
+    #[inline(always)]
+    pub fn update_before_rounds(&mut self, words: [u64; N]) {
+        for (v3, word) in self.v3.iter_mut().zip(words) {
+            *v3 ^= word;
+        }
+    }
+
+    /// Performs [`SipHashState::update_after_rounds`] on each lane with the corresponding element of `words`
+    #[inline(always)]
+    pub fn update_after_rounds(&mut self, words: [u64; N]) {
+        for (v0, word) in self.v0.iter_mut().zip(words) {
+            *v0 ^= word;
+        }
+    }
+
+    /// Performs [`SipHashState::update_before_final`] on each lane
+    #[inline(always)]
+    pub fn update_before_final(&mut self) {
+        for v2 in &mut self.v2 {
+            *v2 ^= 0xff;
+        }
+    }
+
+    /// Performs [`SipHashState::update_before_final128`] on each lane
+    #[inline]
+    pub fn update_before_final128(&mut self) {
+        for v2 in &mut self.v2 {
+            *v2 ^= 0xee;
+        }
+    }
+
+    /// Performs [`SipHashState::update_before_final128_high`] on each lane
+    #[inline]
+    pub fn update_before_final128_high(&mut self) {
+        for v1 in &mut self.v1 {
+            *v1 ^= 0xdd;
+        }
+    }
+
+    /// Performs a single SipHash round on each lane
+    #[inline(always)]
+    pub fn round(&mut self) {
+        let Self { v0, v1, v2, v3 } = self;
+        for i in 0..N {
+            v0[i] = v0[i].wrapping_add(v1[i]);
+            v2[i] = v2[i].wrapping_add(v3[i]);
+            v1[i] = v1[i].rotate_left(13) ^ v0[i];
+            v3[i] = v3[i].rotate_left(16) ^ v2[i];
+            v0[i] = v0[i].rotate_left(32);
+            v2[i] = v2[i].wrapping_add(v1[i]);
+            v0[i] = v0[i].wrapping_add(v3[i]);
+            v1[i] = v1[i].rotate_left(17) ^ v2[i];
+            v3[i] = v3[i].rotate_left(21) ^ v0[i];
+            v2[i] = v2[i].rotate_left(32);
+        }
+    }
+
+    /// Consumes the state and produces the final value of each lane, as if by [`SipHashState::finish`]
+    #[inline(always)]
+    pub fn finish(self) -> [u64; N] {
+        core::array::from_fn(|i| self.v0[i] ^ self.v1[i] ^ self.v2[i] ^ self.v3[i])
+    }
+
+    /// Injests each element of `words` into the corresponding lane and then performs `R` siphash rounds, as if by [`SipHashState::update_and_round`]
+    #[inline(always)]
+    pub fn update_and_round<const R: usize>(&mut self, words: [u64; N]) {
+        self.update_before_rounds(words);
+        for _ in 0..R {
+            self.round();
+        }
+        self.update_after_rounds(words);
+    }
+
+    /// Consumes the state, and performs the full finalization step with `R` finalization rounds on each lane, as if by [`SipHashState::update_and_final`]
+    #[inline(always)]
+    pub fn update_and_final<const R: usize>(mut self) -> [u64; N] {
+        self.update_before_final();
+        for _ in 0..R {
+            self.round();
+        }
+        self.finish()
+    }
+
+    /// Consumes the state, and performs the full SipHash-128 finalization step with `R` finalization rounds on each lane,
+    ///  as if by [`SipHashState::update_and_final128`]
+    #[inline]
+    pub fn update_and_final128<const R: usize>(mut self) -> [u128; N] {
+        self.update_before_final128();
+        for _ in 0..R {
+            self.round();
+        }
+        let l = self.finish();
+        self.update_before_final128_high();
+        for _ in 0..R {
+            self.round();
+        }
+        let h = self.finish();
+
+        core::array::from_fn(|i| (l[i] as u128) | ((h[i] as u128) << 64))
+    }
+
+    /// Replaces the state of lane `n` with `state`
+    ///
+    /// ## Panics
+    /// Panics if `n` is not less than `N`
+    #[inline]
+    pub fn set_lane(&mut self, n: usize, state: SipHashState) {
+        self.set_lane_state(n, state.inspect_state());
+    }
+
+    #[inline(always)]
+    fn lane_state(&self, n: usize) -> [u64; 4] {
+        [self.v0[n], self.v1[n], self.v2[n], self.v3[n]]
+    }
+
+    #[inline(always)]
+    fn set_lane_state(&mut self, n: usize, state: [u64; 4]) {
+        [self.v0[n], self.v1[n], self.v2[n], self.v3[n]] = state;
+    }
+}
+
+/// The number of lanes used by [`hash_batch`] when the target features are known at compile time.
+/// The vectorized round only outperforms the scalar round with a cheap per-lane rotate, so other targets hash one message at a time,
+///  unless the `runtime-dispatch` feature finds AVX2 or AVX-512 on x86.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx512f"
+))]
+const BATCH_LANES: usize = 8;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+const BATCH_LANES: usize = 4;
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+)))]
+const BATCH_LANES: usize = 1;
+
+/// Hashes each of `messages` with SipHash-`C`-`D`, starting from the keyed state `initial`, and stores the results in the corresponding element of `out`.
+pub(crate) fn hash_batch<const C: usize, const D: usize>(
+    initial: SipHashState,
+    messages: &[&[u8]],
+    out: &mut [u64],
+) {
+    assert_eq!(
+        messages.len(),
+        out.len(),
+        "hash_batch requires one output for each message"
+    );
+
+    #[cfg(all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    if BATCH_LANES < 8 {
+        return sys::dispatch::hash_batch::<C, D>(initial, messages, out);
+    }
+
+    hash_batch_lanes::<C, D, BATCH_LANES>(initial, messages, out)
+}
+
+/// Hashes each of `messages` as by [`hash_batch`], in groups of `N` messages. With one lane, each message is hashed on its own by [`SipHasher`].
+#[inline(always)]
+pub(crate) fn hash_batch_lanes<const C: usize, const D: usize, const N: usize>(
+    initial: SipHashState,
+    messages: &[&[u8]],
+    out: &mut [u64],
+) {
+    if N == 1 {
+        for (msg, out) in messages.iter().zip(out) {
+            let mut hasher = SipHasher::<C, D>::from_initial_state(initial);
+            hasher.write(msg);
+            *out = hasher.finish();
+        }
+        return;
+    }
+
+    let (groups, rem) = messages.as_chunks::<N>();
+    let (out_groups, out_rem) = out.as_chunks_mut::<N>();
+    for (group, out) in groups.iter().zip(out_groups) {
+        *out = hash_group::<C, D, N>(initial, group);
+    }
+
+    if !rem.is_empty() {
+        // Pad the remaining messages into a final group
+        let mut group = [&[][..]; N];
+        group[..rem.len()].copy_from_slice(rem);
+        let hashes = hash_group::<C, D, N>(initial, &group);
+        out_rem.copy_from_slice(&hashes[..rem.len()]);
+    }
+}
+
+/// Reads the bytes after the last full word of `msg`, as [`load_partial`] does.
+/// When the message has a full word, this reads the last 8 bytes and shifts out the bytes already ingested instead, which avoids a variable length copy.
+#[inline(always)]
+fn load_tail(msg: &[u8]) -> u64 {
+    let ntail = msg.len() % 8;
+    if msg.len() < 8 {
+        load_partial(msg)
+    } else if ntail == 0 {
+        0
+    } else {
+        u64::from_le_bytes(msg[msg.len() - 8..].try_into().unwrap()) >> (64 - ntail * 8)
+    }
+}
+
+/// Hashes `N` messages in lockstep.
+///
+/// The words that every message has are ingested by all lanes at once, then each lane ingests the rest of its message on its own,
+///  and finally all lanes are finalized at once.
+#[inline(always)]
+fn hash_group<const C: usize, const D: usize, const N: usize>(
+    initial: SipHashState,
+    messages: &[&[u8]; N],
+) -> [u64; N] {
+    let mut state = SipHashStateX::<N>::splat(initial);
+    let shared = messages.iter().map(|msg| msg.len() / 8).min().unwrap_or(0);
+
+    // Read the shared words in blocks of 4 per lane, so that each lane's words are loaded together
+    let blocks = shared / 4;
+    let mut words = [[0u64; N]; 4];
+    for block in 0..blocks {
+        for (i, msg) in messages.iter().enumerate() {
+            let (chunks, _) = msg[block * 32..][..32].as_chunks::<8>();
+            for j in 0..4 {
+                words[j][i] = u64::from_le_bytes(chunks[j]);
+            }
+        }
+        for words in words {
+            state.update_and_round::<C>(words);
+        }
+    }
+    for step in blocks * 4..shared {
+        for (i, msg) in messages.iter().enumerate() {
+            words[0][i] = u64::from_le_bytes(msg[step * 8..][..8].try_into().unwrap());
+        }
+        state.update_and_round::<C>(words[0]);
+    }
+
+    // Only the lanes with more full words than the shortest message are updated on their own, using the scalar implementation to keep moving the lane cheap
+    for (i, msg) in messages.iter().enumerate() {
+        let (chunks, _) = msg[shared * 8..].as_chunks::<8>();
+        if !chunks.is_empty() {
+            let mut lane = backend::Generic::from_state(state.lane_state(i));
+            lane.update_chunks::<C>(chunks);
+            state.set_lane_state(i, lane.inspect_state());
+        }
+    }
+
+    // Every message ends with a final word containing the tail bytes and the length
+    for (i, msg) in messages.iter().enumerate() {
+        words[0][i] = load_tail(msg) | (((msg.len() as u64) & 0xFF) << 56);
+    }
+    state.update_and_round::<C>(words[0]);
+
+    state.update_and_final::<D>()
+}
+
+/// [`MultiKeySipHasher`] computes SipHash-`C`-`D` of a single message under `K` different keys at once.
+///
+/// Each written word is read once and ingested by all `K` instances, which are updated in lockstep as a [`SipHashStateX`].
+/// The result of [`MultiKeySipHasher::finish`] is the same as `K` separate [`SipHasher`]s given the same bytes, one for each key pair.
+/// This is useful for data structures that need several independent hashes of each item, such as bloom filters.
+#[derive(Copy, Clone, Debug)]
+pub struct MultiKeySipHasher<const C: usize, const D: usize, const K: usize> {
+    state: SipHashStateX<K>,
+    tail: u64,
+    ntail: usize,
+    bytes: usize,
+}
+
+impl<const C: usize, const D: usize, const K: usize> MultiKeySipHasher<C, D, K> {
+    /// Constructs a new [`MultiKeySipHasher`], where the `n`th hash uses the keys `k0[n]` and `k1[n]`
+    pub fn new_with_keys(k0: [u64; K], k1: [u64; K]) -> Self {
+        Self {
+            state: SipHashStateX::from_keys(k0, k1),
+            tail: 0u64,
+            ntail: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Convience function that updates every state with the specified word
+    #[inline]
+    pub fn update(&mut self, word: u64) {
+        self.state.update_and_round::<C>([word; K])
+    }
+
+    /// Writes `s` to the message, as if by [`Hasher::write`] on each of the [`SipHasher`]s
+    pub fn write(&mut self, s: &[u8]) {
+        self.bytes = self.bytes.wrapping_add(s.len());
+        let state = &mut self.state;
+        write_buffered(&mut self.tail, &mut self.ntail, s, |words| {
+            for &word in words {
+                state.update_and_round::<C>([u64::from_le_bytes(word); K]);
+            }
+        });
+    }
+
+    /// Finishes each hash, returning the value of the `n`th hash in the `n`th element
+    pub fn finish(&self) -> [u64; K] {
+        let mut state = self.state;
+        state.update_and_round::<C>([self.tail | (((self.bytes as u64) & 0xFF) << 56); K]);
+        state.update_and_final::<D>()
+    }
+}
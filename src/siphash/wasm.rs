@@ -0,0 +1,112 @@
+#[cfg(target_feature = "simd128")]
+pub(super) mod simd128 {
+
+    use super::super::*;
+
+    use core::arch::wasm32::*;
+
+    /// SipHash state stored as `[v0, v2]` and `[v1, v3]` vectors
+    #[derive(Copy, Clone, Debug)]
+    pub struct VectorState(v128, v128);
+
+    #[inline(always)]
+    fn rotate_left<const L: u32, const R: u32>(v: v128) -> v128 {
+        v128_or(i64x2_shl(v, L), u64x2_shr(v, R))
+    }
+
+    impl VectorState {
+        #[inline]
+        pub const fn from_keys(k0: u64, k1: u64) -> Self {
+            Self::from_state([
+                k0 ^ SIPHASH_MAG1,
+                k1 ^ SIPHASH_MAG2,
+                k0 ^ SIPHASH_MAG3,
+                k1 ^ SIPHASH_MAG4,
+            ])
+        }
+
+        #[inline]
+        pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+            Self::from_state([
+                k0 ^ SIPHASH_MAG1,
+                k1 ^ SIPHASH_MAG2 ^ 0xee,
+                k0 ^ SIPHASH_MAG3,
+                k1 ^ SIPHASH_MAG4,
+            ])
+        }
+
+        #[inline]
+        pub const fn from_state([v0, v1, v2, v3]: [u64; 4]) -> Self {
+            Self(u64x2(v0, v2), u64x2(v1, v3))
+        }
+
+        #[inline]
+        pub const fn inspect_state(&self) -> [u64; 4] {
+            let [v0, v2] = unsafe { core::mem::transmute::<v128, [u64; 2]>(self.0) };
+            let [v1, v3] = unsafe { core::mem::transmute::<v128, [u64; 2]>(self.1) };
+
+            [v0, v1, v2, v3]
+        }
+
+        #[inline(always)]
+        pub fn update_before_rounds(&mut self, word: u64) {
+            self.1 = v128_xor(self.1, u64x2(0, word));
+        }
+
+        #[inline(always)]
+        pub fn update_after_rounds(&mut self, word: u64) {
+            self.0 = v128_xor(self.0, u64x2(word, 0));
+        }
+
+        #[inline]
+        pub fn update_before_final(&mut self) {
+            self.0 = v128_xor(self.0, u64x2(0, 0xff));
+        }
+
+        #[inline]
+        pub fn update_before_final128(&mut self) {
+            self.0 = v128_xor(self.0, u64x2(0, 0xee));
+        }
+
+        #[inline]
+        pub fn update_before_final128_high(&mut self) {
+            self.1 = v128_xor(self.1, u64x2(0xdd, 0));
+        }
+
+        #[inline]
+        pub fn finish(self) -> u64 {
+            let s = v128_xor(self.0, self.1);
+            u64x2_extract_lane::<0>(s) ^ u64x2_extract_lane::<1>(s)
+        }
+
+        #[inline(always)]
+        pub fn round(&mut self) {
+            // s0 = [v0,v2], s1 = [v1,v3]. See the x86 backend for the scalar operations each halfround performs.
+            // wasm only has shifts that apply the same count to both lanes, so each per-lane rotate shifts the whole vector by each count and then selects the lanes.
+            let Self(mut s0, mut s1) = *self;
+
+            s0 = i64x2_add(s0, s1);
+            // [rot1, rot3] = [13, 16]. Rotating by 16 is a byte shuffle, so it is folded into the lane select.
+            s1 = i8x16_shuffle::<0, 1, 2, 3, 4, 5, 6, 7, 30, 31, 24, 25, 26, 27, 28, 29>(
+                rotate_left::<13, 51>(s1),
+                s1,
+            );
+            s1 = v128_xor(s1, s0);
+            // permute [v0l,v0h,v2l,v2h] as u32x4 to [v2l,v2h,v0h,v0l] - this rotates v0 32 bits, and then swaps them setting up for the second halfround
+            s0 = i32x4_shuffle::<2, 3, 1, 0>(s0, s0);
+
+            s0 = i64x2_add(s0, s1);
+            // [rot1, rot3] = [17, 21]
+            s1 = i64x2_shuffle::<0, 3>(rotate_left::<17, 47>(s1), rotate_left::<21, 43>(s1));
+            s1 = v128_xor(s1, s0);
+            // Same permute as above, resetting for the next full round
+            s0 = i32x4_shuffle::<2, 3, 1, 0>(s0, s0);
+
+            *self = Self(s0, s1);
+        }
+    }
+}
+
+// The vector round is slower than the scalar one (it has a longer dependency chain than two interleaved scalar rounds), so the scalar state is the default.
+// The vector state is available as [`backend::Simd128`][super::backend::Simd128].
+include!("generic.rs");
@@ -1,6 +1,4 @@
-#[cfg(target_feature = "sse3")]
-#[allow(improper_ctypes_definitions)] // using default ABI was causing extraneous moves into memory, even when functions got inlined
-mod sse {
+pub(super) mod sse {
 
     use super::super::*;
 
@@ -10,88 +8,166 @@ mod sse {
     #[cfg(target_arch = "x86_64")]
     use core::arch::x86_64::*;
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
-    fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i {
-        unsafe { _mm_rolv_epi64(v, count) }
+    use core::marker::PhantomData;
+
+    /// Implementation of the per-lane rotate used by the round function.
+    ///
+    /// Implementations are `#[inline(always)]` so that they are compiled with the target features of the function they are used from.
+    pub trait Rotate {
+        /// Rotates each `u64` lane of `v` left by the corresponding lane of `count`.
+        ///
+        /// # Safety
+        /// The target features named by the implementation must be available on the current CPU
+        unsafe fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i;
     }
 
-    #[cfg(all(
-        target_feature = "avx2",
-        not(all(target_feature = "avx512f", target_feature = "avx512vl"))
-    ))]
-    #[inline]
-    fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i {
-        let lshift = count;
-        let rshift = unsafe { _mm_sub_epi64(_mm_set_epi64x(64, 64), count) };
+    /// Rotate using only SSE2 instructions.
+    ///
+    /// The round using this rotate is 2 to 3 times slower than the scalar round on x86_64 (the round is bound by latency, which the lane selects add to),
+    /// as measured by the `Generic` and `Sse` cases of the benchmarks in `bench/` built without `-C target-cpu`.
+    /// So it is not used by default, and SSE2-only targets use the scalar implementation instead.
+    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(target_feature = "sse2"), allow(dead_code))]
+    pub struct Sse2;
+
+    /// Rotate using the AVX2 variable shifts
+    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(
+        not(any(target_feature = "avx2", feature = "runtime-dispatch")),
+        allow(dead_code)
+    )]
+    pub struct Avx2;
 
-        let left = unsafe { _mm_sllv_epi64(v, lshift) };
-        let right = unsafe { _mm_srlv_epi64(v, rshift) };
+    /// Rotate using the AVX-512 variable rotate. Requires `avx512f` and `avx512vl`
+    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(
+        not(any(
+            all(target_feature = "avx512f", target_feature = "avx512vl"),
+            feature = "runtime-dispatch"
+        )),
+        allow(dead_code)
+    )]
+    pub struct Avx512;
 
-        unsafe { _mm_or_si128(left, right) }
+    impl Rotate for Avx512 {
+        #[inline(always)]
+        unsafe fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i {
+            unsafe { _mm_rolv_epi64(v, count) }
+        }
     }
 
-    #[cfg(not(target_feature = "avx2"))]
-    #[inline]
-    fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i {
-        let lshift = count;
-        let rshift = unsafe { _mm_sub_epi64(_mm_set_epi64x(64, 64), count) };
+    impl Rotate for Avx2 {
+        #[inline(always)]
+        unsafe fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i {
+            let lshift = count;
+            let rshift = unsafe { _mm_sub_epi64(_mm_set_epi64x(64, 64), count) };
 
-        let lsh1 = unsafe { _mm_unpacklo_epi64(lshift, lshift) };
-        let lsh2 = unsafe { _mm_sub_epi64(_mm_unpackhi_epi64(lshift, lshift), lsh1) };
-        let rsh1 = unsafe { _mm_unpackhi_epi64(rshift, rshift) };
-        let rsh2 = unsafe { _mm_sub_epi64(_mm_unpacklo_epi64(rshift, rshift), rsh1) };
+            let left = unsafe { _mm_sllv_epi64(v, lshift) };
+            let right = unsafe { _mm_srlv_epi64(v, rshift) };
 
-        let int1 = unsafe { _mm_sll_epi64(v, lsh1) };
-        let int2 = unsafe { _mm_sll_epi64(int1, lsh2) };
-        let int3 = unsafe { _mm_srl_epi64(v, rsh1) };
-        let int4 = unsafe { _mm_srl_epi64(int3, rsh2) };
+            unsafe { _mm_or_si128(left, right) }
+        }
+    }
 
-        let int5 = unsafe { _mm_shuffle_epi32(int2, 0x1E) };
-        let int6 = unsafe { _mm_shuffle_epi32(int4, 0x1E) };
+    impl Rotate for Sse2 {
+        #[inline(always)]
+        unsafe fn rotate_lanes_epi64(v: __m128i, count: __m128i) -> __m128i {
+            // SSE2 only has shifts that apply the same count to both lanes, so shift the whole vector by each lane's count,
+            // and then select the low lane from the first result and the high lane from the second
+            let rshift = unsafe { _mm_sub_epi64(_mm_set_epi64x(64, 64), count) };
+            let count_hi = unsafe { _mm_unpackhi_epi64(count, count) };
+            let rshift_hi = unsafe { _mm_unpackhi_epi64(rshift, rshift) };
 
-        let sll_res = unsafe { _mm_unpacklo_epi64(int1, int5) };
-        let srl_res = unsafe { _mm_unpacklo_epi64(int3, int6) };
+            let lo = unsafe { _mm_or_si128(_mm_sll_epi64(v, count), _mm_srl_epi64(v, rshift)) };
+            let hi =
+                unsafe { _mm_or_si128(_mm_sll_epi64(v, count_hi), _mm_srl_epi64(v, rshift_hi)) };
 
-        unsafe { _mm_or_si128(sll_res, srl_res) }
+            unsafe { _mm_castpd_si128(_mm_move_sd(_mm_castsi128_pd(hi), _mm_castsi128_pd(lo))) }
+        }
     }
 
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+    pub type StaticRotate = Avx512;
+
+    #[cfg(all(
+        target_feature = "avx2",
+        not(all(target_feature = "avx512f", target_feature = "avx512vl"))
+    ))]
+    pub type StaticRotate = Avx2;
+
+    /// SipHash state stored as `[v0, v2]` and `[v1, v3]` vectors, using `R` to perform rotates
     #[derive(Copy, Clone, Debug)]
-    pub struct SipHashState(__m128i, __m128i);
+    pub struct VectorState<R>(__m128i, __m128i, PhantomData<R>);
 
-    impl SipHashState {
+    #[cfg_attr(not(target_feature = "sse2"), allow(dead_code))]
+    impl<R: Rotate> VectorState<R> {
         #[inline]
         pub const fn from_keys(k0: u64, k1: u64) -> Self {
-            let s0 = unsafe { core::mem::transmute([k0 ^ SIPHASH_MAG1, k0 ^ SIPHASH_MAG3]) };
-            let s1 = unsafe { core::mem::transmute([k1 ^ SIPHASH_MAG2, k1 ^ SIPHASH_MAG4]) };
+            let s0 = unsafe {
+                core::mem::transmute::<[u64; 2], __m128i>([k0 ^ SIPHASH_MAG1, k0 ^ SIPHASH_MAG3])
+            };
+            let s1 = unsafe {
+                core::mem::transmute::<[u64; 2], __m128i>([k1 ^ SIPHASH_MAG2, k1 ^ SIPHASH_MAG4])
+            };
+
+            Self(s0, s1, PhantomData)
+        }
+
+        #[inline]
+        pub const fn from_keys128(k0: u64, k1: u64) -> Self {
+            let s0 = unsafe {
+                core::mem::transmute::<[u64; 2], __m128i>([k0 ^ SIPHASH_MAG1, k0 ^ SIPHASH_MAG3])
+            };
+            let s1 = unsafe {
+                core::mem::transmute::<[u64; 2], __m128i>([
+                    k1 ^ SIPHASH_MAG2 ^ 0xee,
+                    k1 ^ SIPHASH_MAG4,
+                ])
+            };
 
-            Self(s0, s1)
+            Self(s0, s1, PhantomData)
         }
 
         #[inline]
         pub const fn from_state([v0, v1, v2, v3]: [u64; 4]) -> Self {
             unsafe {
                 Self(
-                    core::mem::transmute([v0, v2]),
-                    core::mem::transmute([v1, v3]),
+                    core::mem::transmute::<[u64; 2], __m128i>([v0, v2]),
+                    core::mem::transmute::<[u64; 2], __m128i>([v1, v3]),
+                    PhantomData,
                 )
             }
         }
 
         #[inline]
         pub const fn inspect_state(&self) -> [u64; 4] {
-            let [v0, v2] = unsafe { core::mem::transmute(self.0) };
-            let [v1, v3] = unsafe { core::mem::transmute(self.0) };
+            let [v0, v2] = unsafe { core::mem::transmute::<__m128i, [u64; 2]>(self.0) };
+            let [v1, v3] = unsafe { core::mem::transmute::<__m128i, [u64; 2]>(self.1) };
 
             [v0, v1, v2, v3]
         }
 
-        #[inline]
+        /// Constructs the state from the `[v0, v2]` and `[v1, v3]` vectors
+        #[inline(always)]
+        #[cfg_attr(not(feature = "runtime-dispatch"), allow(dead_code))]
+        pub fn from_vectors((s0, s1): (__m128i, __m128i)) -> Self {
+            Self(s0, s1, PhantomData)
+        }
+
+        /// Returns the `[v0, v2]` and `[v1, v3]` vectors of the state
+        #[inline(always)]
+        #[cfg_attr(not(feature = "runtime-dispatch"), allow(dead_code))]
+        pub fn into_vectors(self) -> (__m128i, __m128i) {
+            (self.0, self.1)
+        }
+
+        #[inline(always)]
         pub fn update_before_rounds(&mut self, word: u64) {
             let val: __m128i = unsafe { _mm_set_epi64x(word as i64, 0) };
             self.1 = unsafe { _mm_xor_si128(self.1, val) };
         }
 
-        #[inline]
+        #[inline(always)]
         pub fn update_after_rounds(&mut self, word: u64) {
             let val: __m128i = unsafe { _mm_set_epi64x(0, word as i64) };
             self.0 = unsafe { _mm_xor_si128(self.0, val) };
@@ -104,19 +180,27 @@ mod sse {
         }
 
         #[inline]
-        pub fn finish(mut self) -> u64 {
-            self.0 = unsafe { _mm_xor_si128(self.0, self.1) };
-            let [l, h]: [u64; 2] = unsafe { core::mem::transmute(self.0) };
-            l ^ h
+        pub fn update_before_final128(&mut self) {
+            let val: __m128i = unsafe { _mm_set_epi64x(0xee, 0) };
+            self.0 = unsafe { _mm_xor_si128(self.0, val) };
+        }
+
+        #[inline]
+        pub fn update_before_final128_high(&mut self) {
+            let val: __m128i = unsafe { _mm_set_epi64x(0, 0xdd) };
+            self.1 = unsafe { _mm_xor_si128(self.1, val) };
         }
 
         #[inline]
-        extern "sysv64" fn halfround(
-            mut s0: __m128i,
-            mut s1: __m128i,
-            rotate: __m128i,
-        ) -> (__m128i, __m128i) {
-            // Compute one half of the round function.with [v0,v2] in s0, and [v1,v3] in s1, and [rot1,rot3] in rotate
+        pub fn finish(self) -> u64 {
+            let s0 = unsafe { _mm_xor_si128(self.0, self.1) };
+            let [l, h]: [u64; 2] = unsafe { core::mem::transmute(s0) };
+            l ^ h
+        }
+
+        #[inline(always)]
+        fn halfround(mut s0: __m128i, mut s1: __m128i, rotate: __m128i) -> (__m128i, __m128i) {
+            // Compute one half of the round function, with [v0,v2] in s0, and [v1,v3] in s1, and [rot1,rot3] in rotate
             // The halfround function is (in scalar ops):
             //  v0 = v0 + v1
             //  v2 = v2 + v3
@@ -129,7 +213,9 @@ mod sse {
             // We vectorize by combining each pair of steps into u64x2 SIMD ops via x86_64 SIMD intrinsics
             // A full round is 2 halfrounds, the first with [rot1,rot3] = [13, 16], and the second with [rot1, rot3] = [17, 21]
             s0 = unsafe { _mm_add_epi64(s0, s1) };
-            s1 = rotate_lanes_epi64(s1, rotate);
+            // SAFETY: `VectorState<R>` is only used with an `R` whose features are either enabled statically (`StaticRotate` and the `backend` types),
+            // or were detected at runtime (`dispatch` and the tests)
+            s1 = unsafe { R::rotate_lanes_epi64(s1, rotate) };
             s1 = unsafe { _mm_xor_si128(s1, s0) };
             // permute [v0l,v0h,v2l,v2h] as u32x4 instead of u64x2 to [v2l,v2h, v0h, v0l] - this rotates v0 32 bits, and then swaps them setting up for the second halfround
             // or resetting for next full round
@@ -138,21 +224,253 @@ mod sse {
             (s0, s1)
         }
 
-        #[inline]
+        #[inline(always)]
         pub fn round(&mut self) {
             // s0 = [v0,v2], s1 = [v1,v3]
-            let Self(s0, s1) = *self;
+            let Self(s0, s1, _) = *self;
             // `_mm_set_epi64x` has reversed parameter order - yields [rot1, rot3] = [13, 16]
             let (s0, s1) = Self::halfround(s0, s1, unsafe { _mm_set_epi64x(16, 13) });
             // [rot1,rot3] = [17,21]
             let (s0, s1) = Self::halfround(s0, s1, unsafe { _mm_set_epi64x(21, 17) });
-            *self = Self(s0, s1);
+            *self = Self(s0, s1, PhantomData);
+        }
+
+        /// Injests each of `words` as if by [`SipHashState::update_and_round`][crate::SipHashState::update_and_round]
+        #[inline(always)]
+        #[cfg_attr(not(feature = "runtime-dispatch"), allow(dead_code))]
+        pub fn update_chunks<const C: usize>(&mut self, words: &[[u8; 8]]) {
+            for &word in words {
+                let word = u64::from_le_bytes(word);
+                self.update_before_rounds(word);
+                for _ in 0..C {
+                    self.round();
+                }
+                self.update_after_rounds(word);
+            }
         }
     }
 }
 
-#[cfg(target_feature = "sse3")]
-pub use sse::*;
+// The vector implementation only outperforms the scalar one when a cheap per-lane rotate is available
+#[cfg(target_feature = "avx2")]
+pub type SipHashState = sse::VectorState<sse::StaticRotate>;
 
-#[cfg(not(target_feature = "sse3"))]
+#[cfg(not(target_feature = "avx2"))]
 include!("generic.rs");
+
+#[cfg(all(not(target_feature = "avx2"), feature = "runtime-dispatch"))]
+impl SipHashState {
+    #[inline(always)]
+    fn into_vectors(self) -> (__m128i, __m128i) {
+        unsafe {
+            (
+                core::mem::transmute::<[u64; 2], __m128i>([self.0, self.1]),
+                core::mem::transmute::<[u64; 2], __m128i>([self.2, self.3]),
+            )
+        }
+    }
+
+    #[inline(always)]
+    fn from_vectors((s0, s1): (__m128i, __m128i)) -> Self {
+        let [v0, v2] = unsafe { core::mem::transmute::<__m128i, [u64; 2]>(s0) };
+        let [v1, v3] = unsafe { core::mem::transmute::<__m128i, [u64; 2]>(s1) };
+
+        Self(v0, v2, v1, v3)
+    }
+}
+
+#[cfg(all(not(target_feature = "avx2"), feature = "runtime-dispatch"))]
+#[cfg(target_arch = "x86")]
+use core::arch::x86::__m128i;
+
+#[cfg(all(not(target_feature = "avx2"), feature = "runtime-dispatch"))]
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::__m128i;
+
+/// Runtime selection of the best available round implementation for bulk updates.
+///
+/// The CPU is queried once, and the result is cached for later calls.
+#[cfg(feature = "runtime-dispatch")]
+pub mod dispatch {
+    use super::sse::{Avx2, Avx512, VectorState};
+    use super::SipHashState;
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    pub(super) const GENERIC: u8 = 1;
+    pub(super) const AVX2: u8 = 2;
+    pub(super) const AVX512: u8 = 3;
+
+    static LEVEL: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    #[cold]
+    fn detect() -> u8 {
+        let level = if std::is_x86_feature_detected!("avx512f")
+            && std::is_x86_feature_detected!("avx512vl")
+        {
+            AVX512
+        } else if std::is_x86_feature_detected!("avx2") {
+            AVX2
+        } else {
+            GENERIC
+        };
+        LEVEL.store(level, Ordering::Relaxed);
+        level
+    }
+
+    #[inline]
+    pub(super) fn detected_level() -> u8 {
+        match LEVEL.load(Ordering::Relaxed) {
+            UNKNOWN => detect(),
+            level => level,
+        }
+    }
+
+    #[target_feature(enable = "avx512f,avx512vl")]
+    fn update_chunks_avx512<const C: usize>(state: &mut SipHashState, words: &[[u8; 8]]) {
+        let mut vectors = VectorState::<Avx512>::from_vectors(state.into_vectors());
+        vectors.update_chunks::<C>(words);
+        *state = SipHashState::from_vectors(vectors.into_vectors());
+    }
+
+    #[target_feature(enable = "avx2")]
+    fn update_chunks_avx2<const C: usize>(state: &mut SipHashState, words: &[[u8; 8]]) {
+        let mut vectors = VectorState::<Avx2>::from_vectors(state.into_vectors());
+        vectors.update_chunks::<C>(words);
+        *state = SipHashState::from_vectors(vectors.into_vectors());
+    }
+
+    /// Injests each of `words` as if by [`SipHashState::update_and_round`][crate::SipHashState::update_and_round],
+    /// using the best round implementation supported by the current CPU.
+    pub fn update_chunks<const C: usize>(state: &mut SipHashState, words: &[[u8; 8]]) {
+        // SAFETY: `detected_level` only reports features that `is_x86_feature_detected!` found
+        unsafe { update_chunks_at::<C>(detected_level(), state, words) }
+    }
+
+    #[target_feature(enable = "avx512f,avx512vl")]
+    fn hash_batch_avx512<const C: usize, const D: usize>(
+        initial: crate::SipHashState,
+        messages: &[&[u8]],
+        out: &mut [u64],
+    ) {
+        crate::siphash::hash_batch_lanes::<C, D, 8>(initial, messages, out)
+    }
+
+    #[target_feature(enable = "avx2")]
+    fn hash_batch_avx2<const C: usize, const D: usize>(
+        initial: crate::SipHashState,
+        messages: &[&[u8]],
+        out: &mut [u64],
+    ) {
+        crate::siphash::hash_batch_lanes::<C, D, 4>(initial, messages, out)
+    }
+
+    /// Hashes each of `messages` as by [`BuildSipHasher::hash_batch`][crate::BuildSipHasher::hash_batch],
+    /// using as many lanes as the best round implementation supported by the current CPU can process at once.
+    pub fn hash_batch<const C: usize, const D: usize>(
+        initial: crate::SipHashState,
+        messages: &[&[u8]],
+        out: &mut [u64],
+    ) {
+        match detected_level() {
+            // SAFETY: `detected_level` only reports features that `is_x86_feature_detected!` found
+            AVX512 => unsafe { hash_batch_avx512::<C, D>(initial, messages, out) },
+            AVX2 => unsafe { hash_batch_avx2::<C, D>(initial, messages, out) },
+            _ => crate::siphash::hash_batch_lanes::<C, D, 1>(initial, messages, out),
+        }
+    }
+
+    /// Injests each of `words` using the round implementation for `level`.
+    ///
+    /// # Safety
+    /// The CPU must support the features required by `level`
+    pub(super) unsafe fn update_chunks_at<const C: usize>(
+        level: u8,
+        state: &mut SipHashState,
+        words: &[[u8; 8]],
+    ) {
+        match level {
+            AVX512 => unsafe { update_chunks_avx512::<C>(state, words) },
+            AVX2 => unsafe { update_chunks_avx2::<C>(state, words) },
+            _ => {
+                for &word in words {
+                    let word = u64::from_le_bytes(word);
+                    state.update_before_rounds(word);
+                    for _ in 0..C {
+                        state.round();
+                    }
+                    state.update_after_rounds(word);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::sse::*;
+    use crate::test::reference;
+
+    const K0: u64 = 0x0706050403020100;
+    const K1: u64 = 0x0f0e0d0c0b0a0908;
+
+    fn words() -> [[u8; 8]; 37] {
+        core::array::from_fn(|i| (i as u64).wrapping_mul(0x9e3779b97f4a7c15).to_le_bytes())
+    }
+
+    /// SipHash-2-4 of [`words`], computed by the reference implementation
+    fn expected() -> u64 {
+        let mut out = [0u8; 8];
+        reference::siphash(2, 4, K0, K1, words().as_flattened(), &mut out);
+        u64::from_le_bytes(out)
+    }
+
+    /// Finishes SipHash-2-4 from the state array after all of [`words`] has been injested
+    fn finish(state: [u64; 4]) -> u64 {
+        let mut state = crate::SipHashState::from_state(state);
+        state.update_and_round::<2>(((words().len() * 8) as u64) << 56);
+        state.update_and_final::<4>()
+    }
+
+    fn check_rotate<R: Rotate>() {
+        let mut state = VectorState::<R>::from_keys(K0, K1);
+        state.update_chunks::<2>(&words());
+        assert_eq!(expected(), finish(state.inspect_state()));
+    }
+
+    #[test]
+    pub fn sse2_round_matches_scalar() {
+        check_rotate::<Sse2>();
+    }
+
+    #[test]
+    pub fn avx2_round_matches_scalar() {
+        if std::is_x86_feature_detected!("avx2") {
+            check_rotate::<Avx2>();
+        }
+    }
+
+    #[test]
+    pub fn avx512_round_matches_scalar() {
+        if std::is_x86_feature_detected!("avx512f") && std::is_x86_feature_detected!("avx512vl") {
+            check_rotate::<Avx512>();
+        }
+    }
+
+    #[cfg(feature = "runtime-dispatch")]
+    #[test]
+    pub fn dispatch_levels_match_scalar() {
+        use super::dispatch::*;
+        use super::SipHashState;
+
+        for level in [GENERIC, AVX2, AVX512] {
+            if level > detected_level() {
+                continue;
+            }
+            let mut state = SipHashState::from_keys(K0, K1);
+            // SAFETY: levels above the detected level were skipped
+            unsafe { update_chunks_at::<2>(level, &mut state, &words()) };
+            assert_eq!(expected(), finish(state.inspect_state()), "level {level}");
+        }
+    }
+}
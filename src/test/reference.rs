@@ -0,0 +1,78 @@
+//! A direct, unoptimized port of the SipHash reference implementation, used as a model for differential testing.
+
+/// The initial state array for the keys `k0` and `k1`
+pub fn initial_state(k0: u64, k1: u64) -> [u64; 4] {
+    [
+        k0 ^ 0x736f6d6570736575,
+        k1 ^ 0x646f72616e646f6d,
+        k0 ^ 0x6c7967656e657261,
+        k1 ^ 0x7465646279746573,
+    ]
+}
+
+/// Applies a single SipRound to the state array
+pub fn sipround(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13);
+    v[1] ^= v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16);
+    v[3] ^= v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21);
+    v[3] ^= v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17);
+    v[1] ^= v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+/// Computes SipHash-*c*-*d* of `msg` into `out`, which must be either 8 or 16 bytes long (selecting 64-bit or 128-bit output)
+pub fn siphash(c: usize, d: usize, k0: u64, k1: u64, msg: &[u8], out: &mut [u8]) {
+    assert!(out.len() == 8 || out.len() == 16);
+    let mut v = initial_state(k0, k1);
+    if out.len() == 16 {
+        v[1] ^= 0xee;
+    }
+
+    let end = msg.len() - (msg.len() % 8);
+    for i in (0..end).step_by(8) {
+        let mut m = 0u64;
+        for j in 0..8 {
+            m |= (msg[i + j] as u64) << (8 * j);
+        }
+        v[3] ^= m;
+        for _ in 0..c {
+            sipround(&mut v);
+        }
+        v[0] ^= m;
+    }
+
+    let mut b = (msg.len() as u64) << 56;
+    for (j, &byte) in msg[end..].iter().enumerate() {
+        b |= (byte as u64) << (8 * j);
+    }
+
+    v[3] ^= b;
+    for _ in 0..c {
+        sipround(&mut v);
+    }
+    v[0] ^= b;
+
+    v[2] ^= if out.len() == 16 { 0xee } else { 0xff };
+    for _ in 0..d {
+        sipround(&mut v);
+    }
+    let r = v[0] ^ v[1] ^ v[2] ^ v[3];
+    out[..8].copy_from_slice(&r.to_le_bytes());
+
+    if out.len() == 16 {
+        v[1] ^= 0xdd;
+        for _ in 0..d {
+            sipround(&mut v);
+        }
+        let r = v[0] ^ v[1] ^ v[2] ^ v[3];
+        out[8..].copy_from_slice(&r.to_le_bytes());
+    }
+}
@@ -0,0 +1,1147 @@
+//! Known-answer test vectors.
+//!
+//! Every SipHash vector uses the reference key (the bytes `0x00..=0x0f`), and every HalfSipHash vector uses the bytes `0x00..=0x07` as the key.
+//! The `n`th vector of each table is the hash of the first `n` bytes of [`REFERENCE_MESSAGE`], and is stored as the bytes of the result.
+//! The SipHash-2-4 and HalfSipHash-2-4 tables are those of the SipHash reference implementation.
+//! The SipHash-1-3 and SipHash-4-8 tables were generated by the same procedure.
+
+/// Low word of the reference key
+pub const REFERENCE_K0: u64 = 0x0706050403020100;
+/// High word of the reference key
+pub const REFERENCE_K1: u64 = 0x0f0e0d0c0b0a0908;
+
+/// Low word of the reference HalfSipHash key
+pub const HALF_REFERENCE_K0: u32 = 0x03020100;
+/// High word of the reference HalfSipHash key
+pub const HALF_REFERENCE_K1: u32 = 0x07060504;
+
+/// The message for the `n`th vector is the first `n` bytes of this array
+pub const REFERENCE_MESSAGE: [u8; 64] = {
+    let mut msg = [0u8; 64];
+    let mut i = 0;
+    while i < 64 {
+        msg[i] = i as u8;
+        i += 1;
+    }
+    msg
+};
+
+/// `vectors_sip64` from the SipHash reference implementation
+pub const SIPHASH_2_4_64: [[u8; 8]; 64] = [
+    [0x31, 0x0e, 0x0e, 0xdd, 0x47, 0xdb, 0x6f, 0x72],
+    [0xfd, 0x67, 0xdc, 0x93, 0xc5, 0x39, 0xf8, 0x74],
+    [0x5a, 0x4f, 0xa9, 0xd9, 0x09, 0x80, 0x6c, 0x0d],
+    [0x2d, 0x7e, 0xfb, 0xd7, 0x96, 0x66, 0x67, 0x85],
+    [0xb7, 0x87, 0x71, 0x27, 0xe0, 0x94, 0x27, 0xcf],
+    [0x8d, 0xa6, 0x99, 0xcd, 0x64, 0x55, 0x76, 0x18],
+    [0xce, 0xe3, 0xfe, 0x58, 0x6e, 0x46, 0xc9, 0xcb],
+    [0x37, 0xd1, 0x01, 0x8b, 0xf5, 0x00, 0x02, 0xab],
+    [0x62, 0x24, 0x93, 0x9a, 0x79, 0xf5, 0xf5, 0x93],
+    [0xb0, 0xe4, 0xa9, 0x0b, 0xdf, 0x82, 0x00, 0x9e],
+    [0xf3, 0xb9, 0xdd, 0x94, 0xc5, 0xbb, 0x5d, 0x7a],
+    [0xa7, 0xad, 0x6b, 0x22, 0x46, 0x2f, 0xb3, 0xf4],
+    [0xfb, 0xe5, 0x0e, 0x86, 0xbc, 0x8f, 0x1e, 0x75],
+    [0x90, 0x3d, 0x84, 0xc0, 0x27, 0x56, 0xea, 0x14],
+    [0xee, 0xf2, 0x7a, 0x8e, 0x90, 0xca, 0x23, 0xf7],
+    [0xe5, 0x45, 0xbe, 0x49, 0x61, 0xca, 0x29, 0xa1],
+    [0xdb, 0x9b, 0xc2, 0x57, 0x7f, 0xcc, 0x2a, 0x3f],
+    [0x94, 0x47, 0xbe, 0x2c, 0xf5, 0xe9, 0x9a, 0x69],
+    [0x9c, 0xd3, 0x8d, 0x96, 0xf0, 0xb3, 0xc1, 0x4b],
+    [0xbd, 0x61, 0x79, 0xa7, 0x1d, 0xc9, 0x6d, 0xbb],
+    [0x98, 0xee, 0xa2, 0x1a, 0xf2, 0x5c, 0xd6, 0xbe],
+    [0xc7, 0x67, 0x3b, 0x2e, 0xb0, 0xcb, 0xf2, 0xd0],
+    [0x88, 0x3e, 0xa3, 0xe3, 0x95, 0x67, 0x53, 0x93],
+    [0xc8, 0xce, 0x5c, 0xcd, 0x8c, 0x03, 0x0c, 0xa8],
+    [0x94, 0xaf, 0x49, 0xf6, 0xc6, 0x50, 0xad, 0xb8],
+    [0xea, 0xb8, 0x85, 0x8a, 0xde, 0x92, 0xe1, 0xbc],
+    [0xf3, 0x15, 0xbb, 0x5b, 0xb8, 0x35, 0xd8, 0x17],
+    [0xad, 0xcf, 0x6b, 0x07, 0x63, 0x61, 0x2e, 0x2f],
+    [0xa5, 0xc9, 0x1d, 0xa7, 0xac, 0xaa, 0x4d, 0xde],
+    [0x71, 0x65, 0x95, 0x87, 0x66, 0x50, 0xa2, 0xa6],
+    [0x28, 0xef, 0x49, 0x5c, 0x53, 0xa3, 0x87, 0xad],
+    [0x42, 0xc3, 0x41, 0xd8, 0xfa, 0x92, 0xd8, 0x32],
+    [0xce, 0x7c, 0xf2, 0x72, 0x2f, 0x51, 0x27, 0x71],
+    [0xe3, 0x78, 0x59, 0xf9, 0x46, 0x23, 0xf3, 0xa7],
+    [0x38, 0x12, 0x05, 0xbb, 0x1a, 0xb0, 0xe0, 0x12],
+    [0xae, 0x97, 0xa1, 0x0f, 0xd4, 0x34, 0xe0, 0x15],
+    [0xb4, 0xa3, 0x15, 0x08, 0xbe, 0xff, 0x4d, 0x31],
+    [0x81, 0x39, 0x62, 0x29, 0xf0, 0x90, 0x79, 0x02],
+    [0x4d, 0x0c, 0xf4, 0x9e, 0xe5, 0xd4, 0xdc, 0xca],
+    [0x5c, 0x73, 0x33, 0x6a, 0x76, 0xd8, 0xbf, 0x9a],
+    [0xd0, 0xa7, 0x04, 0x53, 0x6b, 0xa9, 0x3e, 0x0e],
+    [0x92, 0x59, 0x58, 0xfc, 0xd6, 0x42, 0x0c, 0xad],
+    [0xa9, 0x15, 0xc2, 0x9b, 0xc8, 0x06, 0x73, 0x18],
+    [0x95, 0x2b, 0x79, 0xf3, 0xbc, 0x0a, 0xa6, 0xd4],
+    [0xf2, 0x1d, 0xf2, 0xe4, 0x1d, 0x45, 0x35, 0xf9],
+    [0x87, 0x57, 0x75, 0x19, 0x04, 0x8f, 0x53, 0xa9],
+    [0x10, 0xa5, 0x6c, 0xf5, 0xdf, 0xcd, 0x9a, 0xdb],
+    [0xeb, 0x75, 0x09, 0x5c, 0xcd, 0x98, 0x6c, 0xd0],
+    [0x51, 0xa9, 0xcb, 0x9e, 0xcb, 0xa3, 0x12, 0xe6],
+    [0x96, 0xaf, 0xad, 0xfc, 0x2c, 0xe6, 0x66, 0xc7],
+    [0x72, 0xfe, 0x52, 0x97, 0x5a, 0x43, 0x64, 0xee],
+    [0x5a, 0x16, 0x45, 0xb2, 0x76, 0xd5, 0x92, 0xa1],
+    [0xb2, 0x74, 0xcb, 0x8e, 0xbf, 0x87, 0x87, 0x0a],
+    [0x6f, 0x9b, 0xb4, 0x20, 0x3d, 0xe7, 0xb3, 0x81],
+    [0xea, 0xec, 0xb2, 0xa3, 0x0b, 0x22, 0xa8, 0x7f],
+    [0x99, 0x24, 0xa4, 0x3c, 0xc1, 0x31, 0x57, 0x24],
+    [0xbd, 0x83, 0x8d, 0x3a, 0xaf, 0xbf, 0x8d, 0xb7],
+    [0x0b, 0x1a, 0x2a, 0x32, 0x65, 0xd5, 0x1a, 0xea],
+    [0x13, 0x50, 0x79, 0xa3, 0x23, 0x1c, 0xe6, 0x60],
+    [0x93, 0x2b, 0x28, 0x46, 0xe4, 0xd7, 0x06, 0x66],
+    [0xe1, 0x91, 0x5f, 0x5c, 0xb1, 0xec, 0xa4, 0x6c],
+    [0xf3, 0x25, 0x96, 0x5c, 0xa1, 0x6d, 0x62, 0x9f],
+    [0x57, 0x5f, 0xf2, 0x8e, 0x60, 0x38, 0x1b, 0xe5],
+    [0x72, 0x45, 0x06, 0xeb, 0x4c, 0x32, 0x8a, 0x95],
+];
+
+/// `vectors_sip128` from the SipHash reference implementation
+pub const SIPHASH_2_4_128: [[u8; 16]; 64] = [
+    [
+        0xa3, 0x81, 0x7f, 0x04, 0xba, 0x25, 0xa8, 0xe6, 0x6d, 0xf6, 0x72, 0x14, 0xc7, 0x55, 0x02,
+        0x93,
+    ],
+    [
+        0xda, 0x87, 0xc1, 0xd8, 0x6b, 0x99, 0xaf, 0x44, 0x34, 0x76, 0x59, 0x11, 0x9b, 0x22, 0xfc,
+        0x45,
+    ],
+    [
+        0x81, 0x77, 0x22, 0x8d, 0xa4, 0xa4, 0x5d, 0xc7, 0xfc, 0xa3, 0x8b, 0xde, 0xf6, 0x0a, 0xff,
+        0xe4,
+    ],
+    [
+        0x9c, 0x70, 0xb6, 0x0c, 0x52, 0x67, 0xa9, 0x4e, 0x5f, 0x33, 0xb6, 0xb0, 0x29, 0x85, 0xed,
+        0x51,
+    ],
+    [
+        0xf8, 0x81, 0x64, 0xc1, 0x2d, 0x9c, 0x8f, 0xaf, 0x7d, 0x0f, 0x6e, 0x7c, 0x7b, 0xcd, 0x55,
+        0x79,
+    ],
+    [
+        0x13, 0x68, 0x87, 0x59, 0x80, 0x77, 0x6f, 0x88, 0x54, 0x52, 0x7a, 0x07, 0x69, 0x0e, 0x96,
+        0x27,
+    ],
+    [
+        0x14, 0xee, 0xca, 0x33, 0x8b, 0x20, 0x86, 0x13, 0x48, 0x5e, 0xa0, 0x30, 0x8f, 0xd7, 0xa1,
+        0x5e,
+    ],
+    [
+        0xa1, 0xf1, 0xeb, 0xbe, 0xd8, 0xdb, 0xc1, 0x53, 0xc0, 0xb8, 0x4a, 0xa6, 0x1f, 0xf0, 0x82,
+        0x39,
+    ],
+    [
+        0x3b, 0x62, 0xa9, 0xba, 0x62, 0x58, 0xf5, 0x61, 0x0f, 0x83, 0xe2, 0x64, 0xf3, 0x14, 0x97,
+        0xb4,
+    ],
+    [
+        0x26, 0x44, 0x99, 0x06, 0x0a, 0xd9, 0xba, 0xab, 0xc4, 0x7f, 0x8b, 0x02, 0xbb, 0x6d, 0x71,
+        0xed,
+    ],
+    [
+        0x00, 0x11, 0x0d, 0xc3, 0x78, 0x14, 0x69, 0x56, 0xc9, 0x54, 0x47, 0xd3, 0xf3, 0xd0, 0xfb,
+        0xba,
+    ],
+    [
+        0x01, 0x51, 0xc5, 0x68, 0x38, 0x6b, 0x66, 0x77, 0xa2, 0xb4, 0xdc, 0x6f, 0x81, 0xe5, 0xdc,
+        0x18,
+    ],
+    [
+        0xd6, 0x26, 0xb2, 0x66, 0x90, 0x5e, 0xf3, 0x58, 0x82, 0x63, 0x4d, 0xf6, 0x85, 0x32, 0xc1,
+        0x25,
+    ],
+    [
+        0x98, 0x69, 0xe2, 0x47, 0xe9, 0xc0, 0x8b, 0x10, 0xd0, 0x29, 0x93, 0x4f, 0xc4, 0xb9, 0x52,
+        0xf7,
+    ],
+    [
+        0x31, 0xfc, 0xef, 0xac, 0x66, 0xd7, 0xde, 0x9c, 0x7e, 0xc7, 0x48, 0x5f, 0xe4, 0x49, 0x49,
+        0x02,
+    ],
+    [
+        0x54, 0x93, 0xe9, 0x99, 0x33, 0xb0, 0xa8, 0x11, 0x7e, 0x08, 0xec, 0x0f, 0x97, 0xcf, 0xc3,
+        0xd9,
+    ],
+    [
+        0x6e, 0xe2, 0xa4, 0xca, 0x67, 0xb0, 0x54, 0xbb, 0xfd, 0x33, 0x15, 0xbf, 0x85, 0x23, 0x05,
+        0x77,
+    ],
+    [
+        0x47, 0x3d, 0x06, 0xe8, 0x73, 0x8d, 0xb8, 0x98, 0x54, 0xc0, 0x66, 0xc4, 0x7a, 0xe4, 0x77,
+        0x40,
+    ],
+    [
+        0xa4, 0x26, 0xe5, 0xe4, 0x23, 0xbf, 0x48, 0x85, 0x29, 0x4d, 0xa4, 0x81, 0xfe, 0xae, 0xf7,
+        0x23,
+    ],
+    [
+        0x78, 0x01, 0x77, 0x31, 0xcf, 0x65, 0xfa, 0xb0, 0x74, 0xd5, 0x20, 0x89, 0x52, 0x51, 0x2e,
+        0xb1,
+    ],
+    [
+        0x9e, 0x25, 0xfc, 0x83, 0x3f, 0x22, 0x90, 0x73, 0x3e, 0x93, 0x44, 0xa5, 0xe8, 0x38, 0x39,
+        0xeb,
+    ],
+    [
+        0x56, 0x8e, 0x49, 0x5a, 0xbe, 0x52, 0x5a, 0x21, 0x8a, 0x22, 0x14, 0xcd, 0x3e, 0x07, 0x1d,
+        0x12,
+    ],
+    [
+        0x4a, 0x29, 0xb5, 0x45, 0x52, 0xd1, 0x6b, 0x9a, 0x46, 0x9c, 0x10, 0x52, 0x8e, 0xff, 0x0a,
+        0xae,
+    ],
+    [
+        0xc9, 0xd1, 0x84, 0xdd, 0xd5, 0xa9, 0xf5, 0xe0, 0xcf, 0x8c, 0xe2, 0x9a, 0x9a, 0xbf, 0x69,
+        0x1c,
+    ],
+    [
+        0x2d, 0xb4, 0x79, 0xae, 0x78, 0xbd, 0x50, 0xd8, 0x88, 0x2a, 0x8a, 0x17, 0x8a, 0x61, 0x32,
+        0xad,
+    ],
+    [
+        0x8e, 0xce, 0x5f, 0x04, 0x2d, 0x5e, 0x44, 0x7b, 0x50, 0x51, 0xb9, 0xea, 0xcb, 0x8d, 0x8f,
+        0x6f,
+    ],
+    [
+        0x9c, 0x0b, 0x53, 0xb4, 0xb3, 0xc3, 0x07, 0xe8, 0x7e, 0xae, 0xe0, 0x86, 0x78, 0x14, 0x1f,
+        0x66,
+    ],
+    [
+        0xab, 0xf2, 0x48, 0xaf, 0x69, 0xa6, 0xea, 0xe4, 0xbf, 0xd3, 0xeb, 0x2f, 0x12, 0x9e, 0xeb,
+        0x94,
+    ],
+    [
+        0x06, 0x64, 0xda, 0x16, 0x68, 0x57, 0x4b, 0x88, 0xb9, 0x35, 0xf3, 0x02, 0x73, 0x58, 0xae,
+        0xf4,
+    ],
+    [
+        0xaa, 0x4b, 0x9d, 0xc4, 0xbf, 0x33, 0x7d, 0xe9, 0x0c, 0xd4, 0xfd, 0x3c, 0x46, 0x7c, 0x6a,
+        0xb7,
+    ],
+    [
+        0xea, 0x5c, 0x7f, 0x47, 0x1f, 0xaf, 0x6b, 0xde, 0x2b, 0x1a, 0xd7, 0xd4, 0x68, 0x6d, 0x22,
+        0x87,
+    ],
+    [
+        0x29, 0x39, 0xb0, 0x18, 0x32, 0x23, 0xfa, 0xfc, 0x17, 0x23, 0xde, 0x4f, 0x52, 0xc4, 0x3d,
+        0x35,
+    ],
+    [
+        0x7c, 0x39, 0x56, 0xca, 0x5e, 0xea, 0xfc, 0x3e, 0x36, 0x3e, 0x9d, 0x55, 0x65, 0x46, 0xeb,
+        0x68,
+    ],
+    [
+        0x77, 0xc6, 0x07, 0x71, 0x46, 0xf0, 0x1c, 0x32, 0xb6, 0xb6, 0x9d, 0x5f, 0x4e, 0xa9, 0xff,
+        0xcf,
+    ],
+    [
+        0x37, 0xa6, 0x98, 0x6c, 0xb8, 0x84, 0x7e, 0xdf, 0x09, 0x25, 0xf0, 0xf1, 0x30, 0x9b, 0x54,
+        0xde,
+    ],
+    [
+        0xa7, 0x05, 0xf0, 0xe6, 0x9d, 0xa9, 0xa8, 0xf9, 0x07, 0x24, 0x1a, 0x2e, 0x92, 0x3c, 0x8c,
+        0xc8,
+    ],
+    [
+        0x3d, 0xc4, 0x7d, 0x1f, 0x29, 0xc4, 0x48, 0x46, 0x1e, 0x9e, 0x76, 0xed, 0x90, 0x4f, 0x67,
+        0x11,
+    ],
+    [
+        0x0d, 0x62, 0xbf, 0x01, 0xe6, 0xfc, 0x0e, 0x1a, 0x0d, 0x3c, 0x47, 0x51, 0xc5, 0xd3, 0x69,
+        0x2b,
+    ],
+    [
+        0x8c, 0x03, 0x46, 0x8b, 0xca, 0x7c, 0x66, 0x9e, 0xe4, 0xfd, 0x5e, 0x08, 0x4b, 0xbe, 0xe7,
+        0xb5,
+    ],
+    [
+        0x52, 0x8a, 0x5b, 0xb9, 0x3b, 0xaf, 0x2c, 0x9c, 0x44, 0x73, 0xcc, 0xe5, 0xd0, 0xd2, 0x2b,
+        0xd9,
+    ],
+    [
+        0xdf, 0x6a, 0x30, 0x1e, 0x95, 0xc9, 0x5d, 0xad, 0x97, 0xae, 0x0c, 0xc8, 0xc6, 0x91, 0x3b,
+        0xd8,
+    ],
+    [
+        0x80, 0x11, 0x89, 0x90, 0x2c, 0x85, 0x7f, 0x39, 0xe7, 0x35, 0x91, 0x28, 0x5e, 0x70, 0xb6,
+        0xdb,
+    ],
+    [
+        0xe6, 0x17, 0x34, 0x6a, 0xc9, 0xc2, 0x31, 0xbb, 0x36, 0x50, 0xae, 0x34, 0xcc, 0xca, 0x0c,
+        0x5b,
+    ],
+    [
+        0x27, 0xd9, 0x34, 0x37, 0xef, 0xb7, 0x21, 0xaa, 0x40, 0x18, 0x21, 0xdc, 0xec, 0x5a, 0xdf,
+        0x89,
+    ],
+    [
+        0x89, 0x23, 0x7d, 0x9d, 0xed, 0x9c, 0x5e, 0x78, 0xd8, 0xb1, 0xc9, 0xb1, 0x66, 0xcc, 0x73,
+        0x42,
+    ],
+    [
+        0x4a, 0x6d, 0x80, 0x91, 0xbf, 0x5e, 0x7d, 0x65, 0x11, 0x89, 0xfa, 0x94, 0xa2, 0x50, 0xb1,
+        0x4c,
+    ],
+    [
+        0x0e, 0x33, 0xf9, 0x60, 0x55, 0xe7, 0xae, 0x89, 0x3f, 0xfc, 0x0e, 0x3d, 0xcf, 0x49, 0x29,
+        0x02,
+    ],
+    [
+        0xe6, 0x1c, 0x43, 0x2b, 0x72, 0x0b, 0x19, 0xd1, 0x8e, 0xc8, 0xd8, 0x4b, 0xdc, 0x63, 0x15,
+        0x1b,
+    ],
+    [
+        0xf7, 0xe5, 0xae, 0xf5, 0x49, 0xf7, 0x82, 0xcf, 0x37, 0x90, 0x55, 0xa6, 0x08, 0x26, 0x9b,
+        0x16,
+    ],
+    [
+        0x43, 0x8d, 0x03, 0x0f, 0xd0, 0xb7, 0xa5, 0x4f, 0xa8, 0x37, 0xf2, 0xad, 0x20, 0x1a, 0x64,
+        0x03,
+    ],
+    [
+        0xa5, 0x90, 0xd3, 0xee, 0x4f, 0xbf, 0x04, 0xe3, 0x24, 0x7e, 0x0d, 0x27, 0xf2, 0x86, 0x42,
+        0x3f,
+    ],
+    [
+        0x5f, 0xe2, 0xc1, 0xa1, 0x72, 0xfe, 0x93, 0xc4, 0xb1, 0x5c, 0xd3, 0x7c, 0xae, 0xf9, 0xf5,
+        0x38,
+    ],
+    [
+        0x2c, 0x97, 0x32, 0x5c, 0xbd, 0x06, 0xb3, 0x6e, 0xb2, 0x13, 0x3d, 0xd0, 0x8b, 0x3a, 0x01,
+        0x7c,
+    ],
+    [
+        0x92, 0xc8, 0x14, 0x22, 0x7a, 0x6b, 0xca, 0x94, 0x9f, 0xf0, 0x65, 0x9f, 0x00, 0x2a, 0xd3,
+        0x9e,
+    ],
+    [
+        0xdc, 0xe8, 0x50, 0x11, 0x0b, 0xd8, 0x32, 0x8c, 0xfb, 0xd5, 0x08, 0x41, 0xd6, 0x91, 0x1d,
+        0x87,
+    ],
+    [
+        0x67, 0xf1, 0x49, 0x84, 0xc7, 0xda, 0x79, 0x12, 0x48, 0xe3, 0x2b, 0xb5, 0x92, 0x25, 0x83,
+        0xda,
+    ],
+    [
+        0x19, 0x38, 0xf2, 0xcf, 0x72, 0xd5, 0x4e, 0xe9, 0x7e, 0x94, 0x16, 0x6f, 0xa9, 0x1d, 0x2a,
+        0x36,
+    ],
+    [
+        0x74, 0x48, 0x1e, 0x96, 0x46, 0xed, 0x49, 0xfe, 0x0f, 0x62, 0x24, 0x30, 0x16, 0x04, 0x69,
+        0x8e,
+    ],
+    [
+        0x57, 0xfc, 0xa5, 0xde, 0x98, 0xa9, 0xd6, 0xd8, 0x00, 0x64, 0x38, 0xd0, 0x58, 0x3d, 0x8a,
+        0x1d,
+    ],
+    [
+        0x9f, 0xec, 0xde, 0x1c, 0xef, 0xdc, 0x1c, 0xbe, 0xd4, 0x76, 0x36, 0x74, 0xd9, 0x57, 0x53,
+        0x59,
+    ],
+    [
+        0xe3, 0x04, 0x0c, 0x00, 0xeb, 0x28, 0xf1, 0x53, 0x66, 0xca, 0x73, 0xcb, 0xd8, 0x72, 0xe7,
+        0x40,
+    ],
+    [
+        0x76, 0x97, 0x00, 0x9a, 0x6a, 0x83, 0x1d, 0xfe, 0xcc, 0xa9, 0x1c, 0x59, 0x93, 0x67, 0x0f,
+        0x7a,
+    ],
+    [
+        0x58, 0x53, 0x54, 0x23, 0x21, 0xf5, 0x67, 0xa0, 0x05, 0xd5, 0x47, 0xa4, 0xf0, 0x47, 0x59,
+        0xbd,
+    ],
+    [
+        0x51, 0x50, 0xd1, 0x77, 0x2f, 0x50, 0x83, 0x4a, 0x50, 0x3e, 0x06, 0x9a, 0x97, 0x3f, 0xbd,
+        0x7c,
+    ],
+];
+
+/// SipHash-1-3 with 64-bit output
+pub const SIPHASH_1_3_64: [[u8; 8]; 64] = [
+    [0xdc, 0xc4, 0x0f, 0x05, 0x58, 0x01, 0xac, 0xab],
+    [0x93, 0xca, 0x57, 0x7d, 0xf3, 0x9b, 0xf4, 0xc9],
+    [0x4d, 0xd4, 0xc7, 0x4d, 0x02, 0x9b, 0xcb, 0x82],
+    [0xfb, 0xf7, 0xdd, 0xe7, 0xb8, 0x0a, 0xf8, 0x8b],
+    [0x28, 0x83, 0xd3, 0x88, 0x60, 0x57, 0x75, 0xcf],
+    [0x67, 0x3b, 0x53, 0x49, 0x2f, 0xd5, 0xf9, 0xde],
+    [0xa7, 0x22, 0x9f, 0xc5, 0x50, 0x2b, 0x0d, 0xc5],
+    [0x40, 0x11, 0xb1, 0x9b, 0x98, 0x7d, 0x92, 0xd3],
+    [0x8e, 0x9a, 0x29, 0x8d, 0x11, 0x95, 0x90, 0x36],
+    [0xe4, 0x3d, 0x06, 0x6c, 0xb3, 0x8e, 0xa4, 0x25],
+    [0x7f, 0x09, 0xff, 0x92, 0xee, 0x85, 0xde, 0x79],
+    [0x52, 0xc3, 0x4d, 0xf9, 0xc1, 0x18, 0xc1, 0x70],
+    [0xa2, 0xd9, 0xb4, 0x57, 0xb1, 0x84, 0xa3, 0x78],
+    [0xa7, 0xff, 0x29, 0x12, 0x0c, 0x76, 0x6f, 0x30],
+    [0x34, 0x5d, 0xf9, 0xc0, 0x11, 0xa1, 0x5a, 0x60],
+    [0x56, 0x99, 0x51, 0x2a, 0x6d, 0xd8, 0x20, 0xd3],
+    [0x66, 0x8b, 0x90, 0x7d, 0x1a, 0xdd, 0x4f, 0xcc],
+    [0x0c, 0xd8, 0xdb, 0x63, 0x90, 0x68, 0xf2, 0x9c],
+    [0x3e, 0xe6, 0x73, 0xb4, 0x9c, 0x38, 0xfc, 0x8f],
+    [0x1c, 0x7d, 0x29, 0x8d, 0xe5, 0x9d, 0x1f, 0xf2],
+    [0x40, 0xe0, 0xcc, 0xa6, 0x46, 0x2f, 0xdc, 0xc0],
+    [0x44, 0xf8, 0x45, 0x2b, 0xfe, 0xab, 0x92, 0xb9],
+    [0x2e, 0x87, 0x20, 0xa3, 0x9b, 0x7b, 0xfe, 0x7f],
+    [0x23, 0xc1, 0xe6, 0xda, 0x7f, 0x0e, 0x5a, 0x52],
+    [0x8c, 0x9c, 0x34, 0x67, 0xb2, 0xae, 0x64, 0xf4],
+    [0x79, 0x09, 0x5b, 0x70, 0x28, 0x59, 0xcd, 0x45],
+    [0xa5, 0x13, 0x99, 0xca, 0xe3, 0x35, 0x3e, 0x3a],
+    [0x35, 0x3b, 0xde, 0x4a, 0x4e, 0xc7, 0x1d, 0xa9],
+    [0x0d, 0xd0, 0x6c, 0xef, 0x02, 0xed, 0x0b, 0xfb],
+    [0xf4, 0xe1, 0xb1, 0x4a, 0xb4, 0x3c, 0xd9, 0x88],
+    [0x63, 0xe6, 0xc5, 0x43, 0xd6, 0x11, 0x0f, 0x54],
+    [0xbc, 0xd1, 0x21, 0x8c, 0x1f, 0xdd, 0x70, 0x23],
+    [0x0d, 0xb6, 0xa7, 0x16, 0x6c, 0x7b, 0x15, 0x81],
+    [0xbf, 0xf9, 0x8f, 0x7a, 0xe5, 0xb9, 0x54, 0x4d],
+    [0x3e, 0x75, 0x2a, 0x1f, 0x78, 0x12, 0x9f, 0x75],
+    [0x91, 0x6b, 0x18, 0xbf, 0xbe, 0xa3, 0xa1, 0xce],
+    [0x06, 0x62, 0xa2, 0xad, 0xd3, 0x08, 0xf5, 0x2c],
+    [0x57, 0x30, 0xc3, 0xa3, 0x2d, 0x1c, 0x10, 0xb6],
+    [0xa1, 0x36, 0x3a, 0xae, 0x96, 0x74, 0xf4, 0xb3],
+    [0x92, 0x83, 0x10, 0x7b, 0x54, 0x57, 0x6b, 0x62],
+    [0x31, 0x15, 0xe4, 0x99, 0x32, 0x36, 0xd2, 0xc1],
+    [0x44, 0xd9, 0x1a, 0x3f, 0x92, 0xc1, 0x7c, 0x66],
+    [0x25, 0x88, 0x13, 0xc8, 0xfe, 0x4f, 0x70, 0x65],
+    [0xa6, 0x49, 0x89, 0xc2, 0xd1, 0x80, 0xf2, 0x24],
+    [0x6b, 0x87, 0xf8, 0xfa, 0xed, 0x1c, 0xca, 0xc2],
+    [0x96, 0x21, 0x04, 0x9f, 0xfc, 0x4b, 0x16, 0xc2],
+    [0x23, 0xd6, 0xb1, 0x68, 0x93, 0x9c, 0x6e, 0xa1],
+    [0xfd, 0x14, 0x51, 0x8b, 0x9c, 0x16, 0xfb, 0x49],
+    [0x46, 0x4c, 0x07, 0xdf, 0xf8, 0x43, 0x31, 0x9f],
+    [0xb3, 0x86, 0xcc, 0x12, 0x24, 0xaf, 0xfd, 0xc6],
+    [0x8f, 0x09, 0x52, 0x0a, 0xd1, 0x49, 0xaf, 0x7e],
+    [0x9a, 0x2f, 0x29, 0x9d, 0x55, 0x13, 0xf3, 0x1c],
+    [0x12, 0x1f, 0xf4, 0xa2, 0xdd, 0x30, 0x4a, 0xc4],
+    [0xd0, 0x1e, 0xa7, 0x43, 0x89, 0xe9, 0xfa, 0x36],
+    [0xe6, 0xbc, 0xf0, 0x73, 0x4c, 0xb3, 0x8f, 0x31],
+    [0x80, 0xe9, 0xa7, 0x70, 0x36, 0xbf, 0x7a, 0xa2],
+    [0x75, 0x6d, 0x3c, 0x24, 0xdb, 0xc0, 0xbc, 0xb4],
+    [0x13, 0x15, 0xb7, 0xfd, 0x52, 0xd8, 0xf8, 0x23],
+    [0x08, 0x8a, 0x7d, 0xa6, 0x4d, 0x5f, 0x03, 0x8f],
+    [0x48, 0xf1, 0xe8, 0xb7, 0xe5, 0xd0, 0x9c, 0xd8],
+    [0xee, 0x44, 0xa6, 0xf7, 0xbc, 0xe6, 0xf4, 0xf6],
+    [0xf2, 0x37, 0x18, 0x0f, 0xd8, 0x9a, 0xc5, 0xae],
+    [0xe0, 0x94, 0x66, 0x4b, 0x15, 0xf6, 0xb2, 0xc3],
+    [0xa8, 0xb3, 0xbb, 0xb7, 0x62, 0x90, 0x19, 0x9d],
+];
+
+/// SipHash-1-3 with 128-bit output
+pub const SIPHASH_1_3_128: [[u8; 16]; 64] = [
+    [
+        0xe7, 0x7e, 0xbc, 0xb2, 0x27, 0x88, 0xa5, 0xbe, 0xfd, 0x62, 0xdb, 0x6a, 0xdd, 0x30, 0x30,
+        0x01,
+    ],
+    [
+        0xfc, 0x6f, 0x37, 0x04, 0x60, 0xd3, 0xed, 0xa8, 0x5e, 0x05, 0x73, 0xcc, 0x2b, 0x2f, 0xf0,
+        0x63,
+    ],
+    [
+        0x75, 0x78, 0x7f, 0x09, 0x05, 0x69, 0x83, 0x9b, 0x85, 0x5b, 0xc9, 0x54, 0x8c, 0x6a, 0xea,
+        0x95,
+    ],
+    [
+        0x6b, 0xc5, 0xcc, 0xfa, 0x1e, 0xdc, 0xf7, 0x9f, 0x48, 0x23, 0x18, 0x77, 0x12, 0xeb, 0xd7,
+        0x43,
+    ],
+    [
+        0x0c, 0x78, 0x4e, 0x71, 0xac, 0x2b, 0x28, 0x5a, 0x9f, 0x8e, 0x92, 0xe7, 0x8f, 0xbf, 0x2c,
+        0x25,
+    ],
+    [
+        0xf3, 0x28, 0xdb, 0x89, 0x34, 0x5b, 0x62, 0x0c, 0x79, 0x52, 0x29, 0xa4, 0x26, 0x95, 0x84,
+        0x3e,
+    ],
+    [
+        0xdc, 0xd0, 0x3d, 0x29, 0xf7, 0x43, 0xe7, 0x10, 0x09, 0x51, 0xb0, 0xe8, 0x39, 0x85, 0xa6,
+        0xf8,
+    ],
+    [
+        0x10, 0x84, 0xb9, 0x23, 0xf2, 0xaa, 0xe0, 0xc3, 0xa6, 0x2f, 0x2e, 0xc8, 0x08, 0x48, 0xab,
+        0x77,
+    ],
+    [
+        0xaa, 0x12, 0xfe, 0xe1, 0xd5, 0xe3, 0xda, 0xb4, 0x72, 0x4f, 0x16, 0xab, 0x35, 0xf9, 0xc7,
+        0x99,
+    ],
+    [
+        0x81, 0xdd, 0xb8, 0x04, 0x2c, 0xf3, 0x39, 0x94, 0xf4, 0x72, 0x0e, 0x00, 0x94, 0x13, 0x7c,
+        0x42,
+    ],
+    [
+        0x4f, 0xaa, 0x54, 0x1d, 0x5d, 0x49, 0x8e, 0x89, 0xba, 0x0e, 0xa4, 0xc3, 0x87, 0xb2, 0x2f,
+        0xb4,
+    ],
+    [
+        0x72, 0x3b, 0x9a, 0xf3, 0x55, 0x44, 0x91, 0xdb, 0xb1, 0xd6, 0x63, 0x3d, 0xfc, 0x6e, 0x0c,
+        0x4e,
+    ],
+    [
+        0xe5, 0x3f, 0x92, 0x85, 0x9e, 0x48, 0x19, 0xa8, 0xdc, 0x06, 0x95, 0x73, 0x9f, 0xea, 0x8c,
+        0x65,
+    ],
+    [
+        0xb2, 0xf8, 0x58, 0xc7, 0xc9, 0xea, 0x80, 0x1d, 0x53, 0xd6, 0x03, 0x59, 0x6d, 0x65, 0x78,
+        0x44,
+    ],
+    [
+        0x87, 0xe7, 0x62, 0x68, 0xdb, 0xc9, 0x22, 0x72, 0x26, 0xb0, 0xca, 0x66, 0x5f, 0x64, 0xe3,
+        0x78,
+    ],
+    [
+        0xc1, 0x7e, 0x55, 0x05, 0xb2, 0xbd, 0x52, 0x6c, 0x29, 0x21, 0xcd, 0xec, 0x1e, 0x7e, 0x01,
+        0x09,
+    ],
+    [
+        0xd0, 0xa8, 0xd9, 0x57, 0x15, 0x51, 0x8e, 0xeb, 0xb5, 0x13, 0xb0, 0xf8, 0x3d, 0x9e, 0x17,
+        0x93,
+    ],
+    [
+        0x23, 0x41, 0x26, 0xf9, 0x3f, 0xbb, 0x66, 0x8d, 0x97, 0x51, 0x12, 0xe8, 0xfe, 0xbd, 0xf7,
+        0xec,
+    ],
+    [
+        0xef, 0x42, 0xf0, 0x3d, 0xb7, 0x8f, 0x70, 0x4d, 0x02, 0x3c, 0x44, 0x9f, 0x16, 0xb7, 0x09,
+        0x2b,
+    ],
+    [
+        0xab, 0xf7, 0x62, 0x38, 0xc2, 0x0a, 0xf1, 0x61, 0xb2, 0x31, 0x4b, 0x4d, 0x55, 0x26, 0xbc,
+        0xe9,
+    ],
+    [
+        0x3c, 0x2c, 0x2f, 0x11, 0xbb, 0x90, 0xcf, 0x0b, 0xe3, 0x35, 0xca, 0x9b, 0x2e, 0x91, 0xe9,
+        0xb7,
+    ],
+    [
+        0x2a, 0x7a, 0x68, 0x0f, 0x22, 0xa0, 0x2a, 0x92, 0xf4, 0x51, 0x49, 0xd2, 0x0f, 0xec, 0xe0,
+        0xef,
+    ],
+    [
+        0xc9, 0xa8, 0xd1, 0x30, 0x23, 0x1d, 0xd4, 0x3e, 0x42, 0xe6, 0x45, 0x69, 0x57, 0xf8, 0x37,
+        0x79,
+    ],
+    [
+        0x1d, 0x12, 0x7b, 0x84, 0x40, 0x5c, 0xea, 0xb9, 0x9f, 0xd8, 0x77, 0x5a, 0x9b, 0xe6, 0xc5,
+        0x59,
+    ],
+    [
+        0x9e, 0x4b, 0xf8, 0x37, 0xbc, 0xfd, 0x92, 0xca, 0xce, 0x09, 0xd2, 0x06, 0x1a, 0x84, 0xd0,
+        0x4a,
+    ],
+    [
+        0x39, 0x03, 0x1a, 0x96, 0x5d, 0x73, 0xb4, 0xaf, 0x5a, 0x27, 0x4d, 0x18, 0xf9, 0x73, 0xb1,
+        0xd2,
+    ],
+    [
+        0x7f, 0x4d, 0x0a, 0x12, 0x09, 0xd6, 0x7e, 0x4e, 0xd0, 0x6f, 0x75, 0x38, 0xe1, 0xcf, 0xad,
+        0x64,
+    ],
+    [
+        0xe6, 0x1e, 0xe2, 0x40, 0xfb, 0xdc, 0xce, 0x38, 0x96, 0x9f, 0x4c, 0xd2, 0x49, 0x27, 0xdd,
+        0x93,
+    ],
+    [
+        0x4c, 0x3b, 0xa2, 0xb3, 0x7b, 0x0f, 0xdd, 0x8c, 0xfa, 0x5e, 0x95, 0xc1, 0x89, 0xb2, 0x94,
+        0x14,
+    ],
+    [
+        0xe0, 0x6f, 0xd4, 0xca, 0x06, 0x6f, 0xec, 0xdd, 0x54, 0x06, 0x8a, 0x5a, 0xd8, 0x89, 0x6f,
+        0x86,
+    ],
+    [
+        0x5c, 0xa8, 0x4c, 0x34, 0x13, 0x9c, 0x65, 0x80, 0xa8, 0x8a, 0xf2, 0x49, 0x90, 0x72, 0x07,
+        0x06,
+    ],
+    [
+        0x42, 0xea, 0x96, 0x1c, 0x5b, 0x3c, 0x85, 0x8b, 0x17, 0xc3, 0xe5, 0x50, 0xdf, 0xa7, 0x90,
+        0x10,
+    ],
+    [
+        0x40, 0x6c, 0x44, 0xde, 0xe6, 0x78, 0x57, 0xb2, 0x94, 0x31, 0x60, 0xf3, 0x0c, 0x74, 0x17,
+        0xd3,
+    ],
+    [
+        0xc5, 0xf5, 0x7b, 0xae, 0x13, 0x20, 0xfc, 0xf4, 0xb4, 0xe8, 0x68, 0xe7, 0x1d, 0x56, 0xc6,
+        0x6b,
+    ],
+    [
+        0x04, 0xbf, 0x73, 0x7a, 0x5b, 0x67, 0x6b, 0xe7, 0xc3, 0xde, 0x05, 0x01, 0x7d, 0xf4, 0xbf,
+        0xf9,
+    ],
+    [
+        0x51, 0x63, 0xc9, 0xc0, 0x3f, 0x19, 0x07, 0xea, 0x10, 0x44, 0xed, 0x5c, 0x30, 0x72, 0x7b,
+        0x4f,
+    ],
+    [
+        0x37, 0xa1, 0x10, 0xf0, 0x02, 0x71, 0x8e, 0xda, 0xd2, 0x4b, 0x3f, 0x9e, 0xe4, 0x53, 0xf1,
+        0x40,
+    ],
+    [
+        0xb9, 0x87, 0x7e, 0x38, 0x1a, 0xed, 0xd3, 0xda, 0x08, 0xc3, 0x3e, 0x75, 0xff, 0x23, 0xac,
+        0x10,
+    ],
+    [
+        0x7c, 0x50, 0x04, 0x00, 0x5e, 0xc5, 0xda, 0x4c, 0x5a, 0xc9, 0x44, 0x0e, 0x5c, 0x72, 0x31,
+        0x93,
+    ],
+    [
+        0x81, 0xb8, 0x24, 0x37, 0x83, 0xdb, 0xc6, 0x46, 0xca, 0x9d, 0x0c, 0xd8, 0x2a, 0xbd, 0xb4,
+        0x6c,
+    ],
+    [
+        0x50, 0x57, 0x20, 0x54, 0x3e, 0xb9, 0xb4, 0x13, 0xd5, 0x0b, 0x3c, 0xfa, 0xd9, 0xee, 0xf9,
+        0x38,
+    ],
+    [
+        0x94, 0x5f, 0x59, 0x4d, 0xe7, 0x24, 0x11, 0xe4, 0xd3, 0x35, 0xbe, 0x87, 0x44, 0x56, 0xd8,
+        0xf3,
+    ],
+    [
+        0x37, 0x92, 0x3b, 0x3e, 0x37, 0x17, 0x77, 0xb2, 0x11, 0x70, 0xbf, 0x9d, 0x7e, 0x62, 0xf6,
+        0x02,
+    ],
+    [
+        0x3a, 0xd4, 0xe7, 0xc8, 0x57, 0x64, 0x96, 0x46, 0x11, 0xeb, 0x0a, 0x6c, 0x4d, 0x62, 0xde,
+        0x56,
+    ],
+    [
+        0xcd, 0x91, 0x39, 0x6c, 0x44, 0xaf, 0x4f, 0x51, 0x85, 0x57, 0x8d, 0x9d, 0xd9, 0x80, 0x3f,
+        0x0a,
+    ],
+    [
+        0xfe, 0x28, 0x15, 0x8e, 0x72, 0x7b, 0x86, 0x8f, 0x39, 0x03, 0xc9, 0xac, 0xda, 0x64, 0xa2,
+        0x58,
+    ],
+    [
+        0x40, 0xcc, 0x10, 0xb8, 0x28, 0x8c, 0xe5, 0xf0, 0xbc, 0x3a, 0xc0, 0xb6, 0x8a, 0x0e, 0xeb,
+        0xc8,
+    ],
+    [
+        0x6f, 0x14, 0x90, 0xf5, 0x40, 0x69, 0x9a, 0x3c, 0xd4, 0x97, 0x44, 0x20, 0xec, 0xc9, 0x27,
+        0x37,
+    ],
+    [
+        0xd5, 0x05, 0xf1, 0xb7, 0x5e, 0x1a, 0x84, 0xa6, 0x03, 0xc4, 0x35, 0x83, 0xb2, 0xed, 0x03,
+        0x08,
+    ],
+    [
+        0x49, 0x15, 0x73, 0xcf, 0xd7, 0x2b, 0xb4, 0x68, 0x2b, 0x7c, 0xa5, 0x88, 0x0e, 0x1c, 0x8d,
+        0x6f,
+    ],
+    [
+        0x3e, 0xd6, 0x9c, 0xfe, 0x45, 0xab, 0x40, 0x3f, 0x2f, 0xd2, 0xad, 0x95, 0x9b, 0xa2, 0x76,
+        0x66,
+    ],
+    [
+        0x8b, 0xe8, 0x39, 0xef, 0x1b, 0x20, 0xb5, 0x7c, 0x83, 0xba, 0x7e, 0xb6, 0xa8, 0xc2, 0x2b,
+        0x6a,
+    ],
+    [
+        0x14, 0x09, 0x18, 0x6a, 0xb4, 0x22, 0x31, 0xfe, 0xde, 0xe1, 0x81, 0x62, 0xcf, 0x1c, 0xb4,
+        0xca,
+    ],
+    [
+        0x2b, 0xf3, 0xcc, 0xc2, 0x4a, 0xb6, 0x72, 0xcf, 0x15, 0x1f, 0xb8, 0xd2, 0xf3, 0xf3, 0x06,
+        0x9b,
+    ],
+    [
+        0xb9, 0xb9, 0x3a, 0x28, 0x82, 0xd6, 0x02, 0x5c, 0xdb, 0x8c, 0x56, 0xfa, 0x13, 0xf7, 0x53,
+        0x7b,
+    ],
+    [
+        0xd9, 0x7c, 0xca, 0x36, 0x94, 0xfb, 0x20, 0x6d, 0xb8, 0xbd, 0x1f, 0x36, 0x50, 0xc3, 0x33,
+        0x22,
+    ],
+    [
+        0x94, 0xec, 0x2e, 0x19, 0xa4, 0x0b, 0xe4, 0x1a, 0xf3, 0x94, 0x0d, 0x6b, 0x30, 0xc4, 0x93,
+        0x84,
+    ],
+    [
+        0x4b, 0x41, 0x60, 0x3f, 0x20, 0x9a, 0x04, 0x5b, 0xe1, 0x40, 0xa3, 0x41, 0xa3, 0xdf, 0xfe,
+        0x10,
+    ],
+    [
+        0x23, 0xfb, 0xcb, 0x30, 0x9f, 0x1c, 0xf0, 0x94, 0x89, 0x07, 0x55, 0xab, 0x1b, 0x42, 0x65,
+        0x69,
+    ],
+    [
+        0xe7, 0xd9, 0xb6, 0x56, 0x90, 0x91, 0x8a, 0x2b, 0x23, 0x2f, 0x2f, 0x5c, 0x12, 0xc8, 0x30,
+        0x0e,
+    ],
+    [
+        0xad, 0xe8, 0x3c, 0xf7, 0xe7, 0xf3, 0x84, 0x7b, 0x36, 0xfa, 0x4b, 0x54, 0xb0, 0x0d, 0xce,
+        0x61,
+    ],
+    [
+        0x06, 0x10, 0xc5, 0xf2, 0xee, 0x57, 0x1c, 0x8a, 0xc8, 0x0c, 0xbf, 0xe5, 0x38, 0xbd, 0xf1,
+        0xc7,
+    ],
+    [
+        0x27, 0x1d, 0x5d, 0x00, 0xfb, 0xdb, 0x5d, 0x15, 0x5d, 0x9d, 0xce, 0xa9, 0x7c, 0xb4, 0x02,
+        0x18,
+    ],
+    [
+        0x4c, 0x58, 0x00, 0xe3, 0x4e, 0xfe, 0x42, 0x6f, 0x07, 0x9f, 0x6b, 0x0a, 0xa7, 0x52, 0x60,
+        0xad,
+    ],
+];
+
+/// SipHash-4-8 with 64-bit output
+pub const SIPHASH_4_8_64: [[u8; 8]; 64] = [
+    [0x41, 0xda, 0x38, 0x99, 0x2b, 0x05, 0x79, 0xc8],
+    [0x51, 0xb8, 0x95, 0x52, 0xf9, 0x14, 0x59, 0xc8],
+    [0x92, 0x37, 0x16, 0xf0, 0xbe, 0xdd, 0xc3, 0x33],
+    [0x6a, 0x46, 0xd4, 0x7d, 0x65, 0x47, 0xc1, 0x05],
+    [0xc2, 0x38, 0x59, 0x2b, 0x4a, 0xc1, 0xfa, 0x48],
+    [0xf6, 0xc2, 0xd7, 0xd9, 0xcf, 0x52, 0x47, 0xe1],
+    [0x6b, 0xb6, 0xbc, 0x34, 0xc8, 0x35, 0x55, 0x8e],
+    [0x47, 0xd7, 0x3f, 0x71, 0x5a, 0xbe, 0xfd, 0x4e],
+    [0x20, 0xb5, 0x8b, 0x9c, 0x07, 0x2f, 0xdb, 0x50],
+    [0x36, 0x31, 0x9a, 0xf3, 0x5e, 0xe1, 0x12, 0x53],
+    [0x48, 0xa9, 0xd0, 0xdb, 0x0a, 0x8d, 0x84, 0x8f],
+    [0xcc, 0x69, 0x39, 0x60, 0x36, 0x04, 0x0a, 0x81],
+    [0x4b, 0x6d, 0x68, 0x53, 0x7a, 0xa7, 0x97, 0x61],
+    [0x29, 0x37, 0x96, 0xe9, 0xf2, 0xc9, 0x50, 0x69],
+    [0x88, 0x43, 0x1b, 0xea, 0xa7, 0x62, 0x9a, 0x68],
+    [0xe0, 0xa6, 0xa9, 0x7d, 0xd5, 0x89, 0xd3, 0x83],
+    [0x55, 0x9c, 0xf5, 0x53, 0x80, 0xb2, 0xac, 0x70],
+    [0xd5, 0xb7, 0xc5, 0x11, 0x7a, 0xe3, 0x79, 0x4e],
+    [0x5a, 0x3c, 0x45, 0x46, 0x34, 0xad, 0x10, 0x2b],
+    [0xc0, 0xa4, 0x80, 0xaf, 0xa3, 0x5a, 0x3d, 0xbc],
+    [0x78, 0xc2, 0x27, 0x09, 0xe5, 0x28, 0x4b, 0xc8],
+    [0xef, 0x26, 0x70, 0x46, 0x0d, 0xeb, 0xd6, 0x9d],
+    [0xd9, 0x76, 0xef, 0x86, 0xa9, 0xd0, 0x84, 0xd8],
+    [0xe3, 0xd9, 0x81, 0x18, 0x19, 0xea, 0xd0, 0xe8],
+    [0x89, 0x33, 0x3c, 0xb5, 0x3e, 0xea, 0xec, 0x16],
+    [0x31, 0x15, 0x6c, 0x5f, 0x64, 0x73, 0x49, 0xc6],
+    [0xa5, 0x4c, 0xce, 0x35, 0x35, 0x76, 0x32, 0xa4],
+    [0x06, 0x5d, 0x89, 0x25, 0xc0, 0xa7, 0xd2, 0xfe],
+    [0x2b, 0xbb, 0xaa, 0x82, 0x22, 0x1a, 0x3a, 0x8b],
+    [0x87, 0x0b, 0xfb, 0xce, 0x64, 0x09, 0x7b, 0x70],
+    [0x40, 0xd8, 0xe0, 0xf9, 0x64, 0x95, 0xee, 0x8b],
+    [0x79, 0xfc, 0xa7, 0xf4, 0x0b, 0xfa, 0xdf, 0x12],
+    [0x00, 0x0b, 0xfb, 0xf2, 0x2f, 0x76, 0x9e, 0xd2],
+    [0x40, 0x68, 0x55, 0x91, 0xf8, 0xe5, 0x22, 0xfa],
+    [0x2b, 0xe6, 0xfe, 0x74, 0xd8, 0x14, 0x9d, 0x0d],
+    [0xba, 0x7e, 0x2f, 0x0e, 0x0b, 0x75, 0x60, 0xed],
+    [0x02, 0xe9, 0xe3, 0x84, 0xed, 0xa7, 0xe1, 0x97],
+    [0xc4, 0xe8, 0x0a, 0x62, 0x95, 0x27, 0x63, 0xb6],
+    [0x83, 0x27, 0xed, 0xc6, 0x5d, 0x5c, 0x6d, 0xd3],
+    [0x79, 0xfc, 0x64, 0xd1, 0x64, 0xa4, 0x2f, 0xc0],
+    [0x15, 0x4a, 0x75, 0x11, 0xcb, 0xfc, 0x61, 0x4e],
+    [0x8b, 0x14, 0x8d, 0x7c, 0xec, 0xa0, 0xe6, 0x6f],
+    [0xdf, 0xee, 0x69, 0xb6, 0x54, 0xc4, 0x03, 0xfa],
+    [0xc5, 0x8f, 0x36, 0xa6, 0x69, 0x7b, 0xb7, 0xc9],
+    [0xa6, 0xc5, 0xbe, 0x9c, 0x05, 0xc6, 0x31, 0x21],
+    [0xb5, 0x8a, 0x87, 0x59, 0xfb, 0xcd, 0x89, 0x31],
+    [0xd7, 0x68, 0x3a, 0x67, 0x04, 0xcc, 0xc4, 0x25],
+    [0xcb, 0x6a, 0xe6, 0xe1, 0xe5, 0xa2, 0x44, 0x8d],
+    [0x6e, 0x26, 0x69, 0x5b, 0x3a, 0x3a, 0x51, 0x73],
+    [0x78, 0x71, 0x07, 0xcf, 0x9f, 0x33, 0xac, 0x4a],
+    [0x16, 0x75, 0x90, 0xda, 0xd9, 0x7b, 0x74, 0x84],
+    [0x00, 0x6b, 0x68, 0x1e, 0xf0, 0x6b, 0xf3, 0x06],
+    [0x1c, 0x9b, 0x30, 0x02, 0x66, 0xef, 0xcf, 0xa6],
+    [0x28, 0x8d, 0x2f, 0x88, 0xd1, 0xb0, 0xb3, 0x4b],
+    [0xe0, 0x11, 0x06, 0xbd, 0xac, 0xf5, 0x6b, 0xfe],
+    [0xc0, 0x10, 0x1f, 0x0e, 0x5b, 0x6e, 0x03, 0x28],
+    [0xc3, 0xa7, 0x91, 0x45, 0x5b, 0x1b, 0x1c, 0x0a],
+    [0x57, 0x07, 0xaf, 0xe1, 0x9e, 0x0b, 0x3a, 0x0f],
+    [0xe6, 0x5a, 0x72, 0x29, 0xfe, 0x53, 0x59, 0x4f],
+    [0x00, 0x2f, 0x9d, 0xb9, 0xab, 0x1a, 0xaf, 0x4c],
+    [0x59, 0x28, 0xcb, 0x50, 0x44, 0xc1, 0x06, 0x06],
+    [0xd5, 0x38, 0x01, 0x96, 0x7b, 0x85, 0x73, 0x21],
+    [0x05, 0xdb, 0x36, 0x4f, 0x1a, 0x09, 0x99, 0xcc],
+    [0xe6, 0x77, 0x84, 0xbc, 0x55, 0x03, 0xde, 0x23],
+];
+
+/// SipHash-4-8 with 128-bit output
+pub const SIPHASH_4_8_128: [[u8; 16]; 64] = [
+    [
+        0x1f, 0x64, 0xce, 0x58, 0x6d, 0xa9, 0x04, 0xe9, 0xcf, 0xec, 0xe8, 0x54, 0x83, 0xa7, 0x0a,
+        0x6c,
+    ],
+    [
+        0x47, 0x34, 0x5d, 0xa8, 0xef, 0x4c, 0x79, 0x47, 0x6a, 0xf2, 0x7c, 0xa7, 0x91, 0xc7, 0xa2,
+        0x80,
+    ],
+    [
+        0xe1, 0x49, 0x5f, 0xa3, 0x96, 0xca, 0x2d, 0xc6, 0x22, 0x73, 0x81, 0x5f, 0x18, 0x82, 0x21,
+        0xa4,
+    ],
+    [
+        0xc7, 0xa2, 0x73, 0x84, 0x4a, 0xc5, 0x4e, 0x83, 0x5a, 0x9c, 0xb6, 0x7f, 0x81, 0x05, 0x76,
+        0x02,
+    ],
+    [
+        0x54, 0x1f, 0x52, 0xbb, 0xf4, 0x3e, 0xce, 0x4e, 0x2a, 0x95, 0xc8, 0xe0, 0x1f, 0x65, 0x6d,
+        0xef,
+    ],
+    [
+        0x17, 0x97, 0x3b, 0xd4, 0x0d, 0xf3, 0x48, 0x15, 0x24, 0x4f, 0x99, 0x0c, 0xbf, 0x12, 0xbe,
+        0x5d,
+    ],
+    [
+        0x6b, 0x0b, 0x36, 0x0d, 0x56, 0x32, 0x80, 0xcd, 0xb1, 0x7d, 0x56, 0xc9, 0x08, 0xe1, 0xf5,
+        0xff,
+    ],
+    [
+        0xed, 0x00, 0xe1, 0x3b, 0x18, 0x4b, 0xf1, 0xc2, 0x72, 0x6b, 0x8b, 0x54, 0xff, 0xd2, 0xee,
+        0xe0,
+    ],
+    [
+        0xa7, 0xd9, 0x46, 0x13, 0x8f, 0xf9, 0xed, 0xf5, 0x36, 0x4a, 0x5a, 0x23, 0xaf, 0xca, 0xe0,
+        0x63,
+    ],
+    [
+        0x9e, 0x73, 0x14, 0xb7, 0x54, 0x5c, 0xec, 0xa3, 0x8b, 0x9a, 0x55, 0x49, 0xe4, 0xfb, 0x0b,
+        0xe8,
+    ],
+    [
+        0x58, 0x6c, 0x62, 0xc6, 0x84, 0x89, 0xd1, 0x68, 0xae, 0xe6, 0x5b, 0x88, 0x9a, 0xb9, 0x12,
+        0x75,
+    ],
+    [
+        0xe6, 0x71, 0x52, 0xa6, 0x4c, 0xa3, 0xd1, 0x47, 0xc4, 0xab, 0x84, 0x1e, 0x2f, 0x2e, 0x7a,
+        0x99,
+    ],
+    [
+        0x7f, 0x1c, 0x7a, 0xea, 0x90, 0x8d, 0xe5, 0x2e, 0x3e, 0x9e, 0x08, 0x83, 0xee, 0xa8, 0x16,
+        0xaf,
+    ],
+    [
+        0xde, 0x82, 0x7a, 0xbf, 0x92, 0xb7, 0x33, 0x92, 0x3f, 0x35, 0x33, 0x0d, 0xb5, 0xef, 0x4a,
+        0x34,
+    ],
+    [
+        0x59, 0x75, 0x63, 0x64, 0x0f, 0x37, 0x9a, 0xc5, 0x37, 0x67, 0x8e, 0xe2, 0x35, 0x4c, 0x7d,
+        0xf9,
+    ],
+    [
+        0x28, 0x4d, 0x03, 0x30, 0x3a, 0x45, 0x3a, 0x59, 0x3d, 0x78, 0xf7, 0xfa, 0xdc, 0x90, 0x62,
+        0xcb,
+    ],
+    [
+        0x91, 0x4a, 0xc7, 0xa2, 0x59, 0x7f, 0x63, 0xb7, 0xc0, 0xfd, 0xe5, 0xab, 0x8d, 0x4e, 0xad,
+        0x9c,
+    ],
+    [
+        0x0d, 0x51, 0x15, 0xa4, 0x4b, 0xa4, 0x55, 0xee, 0x3a, 0x45, 0x3b, 0x95, 0xce, 0x87, 0xc3,
+        0xcb,
+    ],
+    [
+        0x54, 0x9b, 0x93, 0x9d, 0x0b, 0xf1, 0xd8, 0x94, 0x83, 0x37, 0x88, 0x5a, 0x84, 0xce, 0x79,
+        0x14,
+    ],
+    [
+        0x6c, 0x17, 0x97, 0x69, 0xcd, 0x34, 0x8a, 0xeb, 0xd2, 0xfb, 0x13, 0x57, 0x8c, 0x72, 0xb4,
+        0x6c,
+    ],
+    [
+        0xaa, 0xd0, 0x36, 0xc1, 0x38, 0xc9, 0x57, 0xe0, 0x68, 0x2a, 0x00, 0xee, 0x2f, 0x86, 0x40,
+        0x8b,
+    ],
+    [
+        0x21, 0xb1, 0xee, 0xc4, 0x2f, 0xb6, 0x70, 0xbf, 0xee, 0x90, 0x44, 0xff, 0x4e, 0xd7, 0x3a,
+        0x26,
+    ],
+    [
+        0x05, 0x93, 0xa1, 0xd6, 0x29, 0x97, 0xed, 0x37, 0x46, 0x53, 0xc9, 0x17, 0x46, 0x3f, 0x14,
+        0xeb,
+    ],
+    [
+        0x11, 0x3d, 0x31, 0x62, 0x77, 0x19, 0xf9, 0x1e, 0xa0, 0xf1, 0xff, 0xc6, 0x86, 0x57, 0xe2,
+        0x4e,
+    ],
+    [
+        0xb3, 0x39, 0x4c, 0xf7, 0x2d, 0xe0, 0x6a, 0xdd, 0x0e, 0x73, 0x14, 0xf0, 0xc2, 0x52, 0xc4,
+        0xd6,
+    ],
+    [
+        0x92, 0x2a, 0x98, 0xda, 0x9d, 0x35, 0xc3, 0x41, 0xe2, 0x45, 0x6b, 0xe4, 0xcd, 0x63, 0x89,
+        0xd2,
+    ],
+    [
+        0x59, 0x6b, 0x62, 0x30, 0xf7, 0x57, 0xb3, 0x4a, 0xa2, 0xdc, 0xea, 0x50, 0xcb, 0xb2, 0x8d,
+        0x4d,
+    ],
+    [
+        0xc2, 0x4e, 0xe4, 0x97, 0xd5, 0x5b, 0x7e, 0x80, 0x06, 0x84, 0xdf, 0x75, 0x65, 0x59, 0xee,
+        0x48,
+    ],
+    [
+        0x5e, 0x9c, 0xb6, 0xa1, 0x36, 0x68, 0x1e, 0xd4, 0x5e, 0x2b, 0x9d, 0xe4, 0xdc, 0x01, 0x81,
+        0x77,
+    ],
+    [
+        0xbf, 0xfa, 0x39, 0xca, 0x86, 0x56, 0xd3, 0x04, 0x79, 0x33, 0xed, 0xfe, 0x9d, 0x81, 0x78,
+        0xb2,
+    ],
+    [
+        0x18, 0x22, 0x94, 0x18, 0xa1, 0xd0, 0x79, 0x5a, 0x35, 0x7a, 0x80, 0x3a, 0x81, 0x34, 0xae,
+        0xa3,
+    ],
+    [
+        0x4a, 0x3e, 0x96, 0xff, 0x53, 0x47, 0x4e, 0x2e, 0x73, 0x7b, 0x69, 0x57, 0x1a, 0x77, 0xb0,
+        0x6e,
+    ],
+    [
+        0xfe, 0xd5, 0xf0, 0xf9, 0xd0, 0x37, 0x72, 0x84, 0x2e, 0x2f, 0x57, 0x2f, 0x63, 0xf1, 0x94,
+        0x50,
+    ],
+    [
+        0x39, 0x33, 0x58, 0x86, 0xc1, 0xf9, 0x42, 0x63, 0xc4, 0x0c, 0x66, 0x29, 0xc6, 0xbc, 0x44,
+        0x6f,
+    ],
+    [
+        0xee, 0xa5, 0xf9, 0x3b, 0xb3, 0x87, 0x10, 0xb0, 0x8b, 0x2c, 0x46, 0x97, 0x19, 0x8b, 0xbf,
+        0x9f,
+    ],
+    [
+        0x80, 0x6e, 0xc7, 0xb6, 0x70, 0x4f, 0x72, 0x0e, 0x37, 0x43, 0x12, 0x06, 0x61, 0x66, 0xd4,
+        0x3a,
+    ],
+    [
+        0x6e, 0x69, 0xed, 0x9d, 0xf0, 0xc9, 0x39, 0xb4, 0x9d, 0xaf, 0xee, 0xae, 0x60, 0x47, 0xb2,
+        0xa2,
+    ],
+    [
+        0x93, 0xc7, 0x7b, 0xf2, 0x98, 0xb6, 0xf9, 0xc7, 0x94, 0xa2, 0x30, 0x17, 0x7f, 0x2f, 0xd7,
+        0x38,
+    ],
+    [
+        0xff, 0xad, 0x9c, 0xd9, 0x8c, 0x2a, 0xa8, 0x75, 0xda, 0xff, 0x3a, 0x2a, 0x4c, 0xe6, 0x0c,
+        0xe6,
+    ],
+    [
+        0x4d, 0x99, 0x2f, 0xfd, 0xf9, 0x4a, 0x93, 0xcd, 0xcd, 0x64, 0xef, 0x76, 0x57, 0xf5, 0x10,
+        0xe3,
+    ],
+    [
+        0x32, 0x70, 0x62, 0x4e, 0x24, 0xe0, 0xa1, 0x1e, 0xa1, 0x86, 0xe0, 0x96, 0xbe, 0x1b, 0xce,
+        0x9b,
+    ],
+    [
+        0x31, 0xe8, 0xbb, 0xe0, 0xcb, 0x4e, 0xff, 0x51, 0x1f, 0xff, 0xc7, 0xc4, 0x09, 0x34, 0x31,
+        0x77,
+    ],
+    [
+        0xcb, 0xe1, 0x7d, 0x05, 0x87, 0x9a, 0xd9, 0x07, 0x64, 0x8a, 0x12, 0xa0, 0x70, 0x16, 0xab,
+        0x5b,
+    ],
+    [
+        0x88, 0x48, 0xd4, 0x43, 0x70, 0xe9, 0x8b, 0xe2, 0xd5, 0xd2, 0x8b, 0x46, 0x36, 0x6a, 0x0a,
+        0xfc,
+    ],
+    [
+        0xb7, 0xff, 0xd1, 0xb2, 0x42, 0x10, 0x76, 0xa9, 0x0c, 0xb5, 0xcf, 0x65, 0x54, 0x09, 0x5e,
+        0x0c,
+    ],
+    [
+        0x6a, 0x6b, 0x66, 0x6c, 0xd5, 0x23, 0xa8, 0xf6, 0xbb, 0xd8, 0x84, 0xfe, 0x1f, 0xd1, 0x05,
+        0x0c,
+    ],
+    [
+        0xa8, 0xfe, 0x8a, 0x83, 0x50, 0xfb, 0xf5, 0xc8, 0x05, 0xf1, 0x8c, 0xbd, 0x30, 0x13, 0x62,
+        0x24,
+    ],
+    [
+        0xcc, 0xe7, 0x11, 0x7a, 0xee, 0x82, 0x36, 0xf2, 0xeb, 0x3a, 0x96, 0x94, 0xd5, 0x7e, 0x62,
+        0xb5,
+    ],
+    [
+        0x3a, 0x25, 0xf0, 0xe4, 0xfc, 0x28, 0xb7, 0x0c, 0x6b, 0x30, 0x90, 0xba, 0xfe, 0xf6, 0x9f,
+        0x04,
+    ],
+    [
+        0x3f, 0x05, 0xe6, 0x26, 0x74, 0x9f, 0xc4, 0x8b, 0x81, 0x06, 0xf8, 0xe4, 0x44, 0x31, 0xdd,
+        0x4a,
+    ],
+    [
+        0x76, 0x68, 0x79, 0xf9, 0x76, 0x72, 0x16, 0x5c, 0x0a, 0xff, 0xd5, 0xfa, 0xdc, 0x77, 0x34,
+        0x5b,
+    ],
+    [
+        0x43, 0x71, 0xa0, 0x5a, 0xb6, 0x6c, 0x59, 0x8b, 0xc9, 0xc2, 0x84, 0x94, 0xa1, 0xdd, 0x2f,
+        0x0e,
+    ],
+    [
+        0x65, 0xf8, 0x5b, 0xd3, 0xa2, 0xa5, 0xf1, 0xba, 0x1f, 0x22, 0xb6, 0xef, 0xd6, 0xe0, 0x02,
+        0x66,
+    ],
+    [
+        0x76, 0xcf, 0x61, 0xda, 0xe5, 0x4b, 0x22, 0xef, 0xca, 0x6a, 0x9f, 0x22, 0x8a, 0xaf, 0x66,
+        0x11,
+    ],
+    [
+        0x6c, 0xdc, 0xc2, 0xe3, 0x9f, 0xdb, 0xa2, 0x9f, 0x88, 0x53, 0x90, 0xab, 0x9d, 0xa4, 0x84,
+        0xda,
+    ],
+    [
+        0xe1, 0xee, 0xac, 0xea, 0xcc, 0x3b, 0x67, 0xb2, 0xd8, 0xe4, 0xe2, 0x61, 0x7b, 0x2f, 0xaa,
+        0x5a,
+    ],
+    [
+        0x0b, 0xd2, 0x9f, 0x6f, 0x4c, 0xe1, 0x0f, 0x17, 0x78, 0xd6, 0xb0, 0x2e, 0xd5, 0xab, 0x5a,
+        0x6d,
+    ],
+    [
+        0xad, 0x18, 0x9f, 0x15, 0x6a, 0x52, 0x26, 0x7c, 0xe0, 0x87, 0x45, 0x83, 0x5b, 0x65, 0xa6,
+        0x07,
+    ],
+    [
+        0x0f, 0x6b, 0x99, 0x71, 0x72, 0x25, 0x66, 0xd4, 0x3d, 0xec, 0x6b, 0x99, 0xe3, 0x1c, 0x21,
+        0x8f,
+    ],
+    [
+        0xa1, 0xa4, 0xc8, 0xfa, 0x4f, 0x3d, 0xf4, 0x66, 0xd3, 0xf3, 0x9c, 0x6f, 0x3d, 0x9e, 0x1a,
+        0x74,
+    ],
+    [
+        0x3b, 0x1a, 0x3d, 0xb8, 0x8c, 0xf0, 0xc2, 0x1f, 0xc1, 0xa6, 0xd8, 0xa7, 0x2d, 0x9e, 0xf9,
+        0x1d,
+    ],
+    [
+        0xd1, 0x48, 0x68, 0x02, 0xef, 0xc0, 0x00, 0x28, 0x56, 0xc3, 0x63, 0x5a, 0x8a, 0x69, 0x2e,
+        0xe5,
+    ],
+    [
+        0xee, 0xa1, 0x5f, 0x8f, 0x7c, 0xae, 0x19, 0x99, 0xfd, 0x56, 0x49, 0x31, 0xc2, 0x2c, 0x1c,
+        0x3c,
+    ],
+    [
+        0x63, 0xf5, 0xae, 0x63, 0x28, 0xc4, 0xdb, 0x93, 0x20, 0x79, 0x61, 0xee, 0x90, 0x6b, 0xd4,
+        0xa5,
+    ],
+];
+
+/// `vectors_hsip32` from the SipHash reference implementation
+pub const HALFSIPHASH_2_4_32: [[u8; 4]; 64] = [
+    [0xa9, 0x35, 0x9f, 0x5b],
+    [0x27, 0x47, 0x5a, 0xb8],
+    [0xfa, 0x62, 0xa6, 0x03],
+    [0x8a, 0xfe, 0xe7, 0x04],
+    [0x2a, 0x6e, 0x46, 0x89],
+    [0xc5, 0xfa, 0xb6, 0x69],
+    [0x58, 0x63, 0xfc, 0x23],
+    [0x8b, 0xcf, 0x63, 0xc5],
+    [0xd0, 0xb8, 0x84, 0x8f],
+    [0xf8, 0x06, 0xe7, 0x79],
+    [0x94, 0xb0, 0x79, 0x34],
+    [0x08, 0x08, 0x30, 0x50],
+    [0x57, 0xf0, 0x87, 0x2f],
+    [0x77, 0xe6, 0x63, 0xff],
+    [0xd6, 0xff, 0xf8, 0x7c],
+    [0x74, 0xfe, 0x2b, 0x97],
+    [0xd9, 0xb5, 0xac, 0x84],
+    [0xc4, 0x74, 0x64, 0x5b],
+    [0x46, 0x5b, 0x8d, 0x9b],
+    [0x7b, 0xef, 0xe3, 0x87],
+    [0xe3, 0x4d, 0x10, 0x45],
+    [0x61, 0x3f, 0x62, 0xb3],
+    [0x70, 0xf3, 0x67, 0xfe],
+    [0xe6, 0xad, 0xb8, 0xbd],
+    [0x27, 0x40, 0x0c, 0x63],
+    [0x26, 0x78, 0x78, 0x75],
+    [0x4f, 0x56, 0x7b, 0x5f],
+    [0x3a, 0xb0, 0xe6, 0x69],
+    [0xb0, 0x64, 0x40, 0x00],
+    [0xff, 0x67, 0x0f, 0xb4],
+    [0x50, 0x9e, 0x33, 0x8b],
+    [0x5d, 0x58, 0x9f, 0x1a],
+    [0xfe, 0xe7, 0x21, 0x12],
+    [0x33, 0x75, 0x32, 0x59],
+    [0x6a, 0x43, 0x4f, 0x8c],
+    [0xfe, 0x28, 0xb7, 0x29],
+    [0xe7, 0x5c, 0xc6, 0xec],
+    [0x69, 0x7e, 0x8d, 0x54],
+    [0x63, 0x68, 0x8b, 0x0f],
+    [0x65, 0x0b, 0x62, 0xb4],
+    [0xb6, 0xbc, 0x18, 0x40],
+    [0x5d, 0x07, 0x45, 0x05],
+    [0x24, 0x42, 0xfd, 0x2e],
+    [0x7b, 0xb7, 0x86, 0x3a],
+    [0x77, 0x05, 0xd5, 0x48],
+    [0xd7, 0x52, 0x08, 0xb1],
+    [0xb6, 0xd4, 0x99, 0xc8],
+    [0x08, 0x92, 0x20, 0x2e],
+    [0x69, 0xe1, 0x2c, 0xe3],
+    [0x8d, 0xb5, 0x80, 0xe5],
+    [0x36, 0x97, 0x64, 0xc6],
+    [0x01, 0x6e, 0x02, 0x04],
+    [0x3b, 0x85, 0xf3, 0xd4],
+    [0xfe, 0xdb, 0x66, 0xbe],
+    [0x1e, 0x69, 0x2a, 0x3a],
+    [0xc6, 0x89, 0x84, 0xc0],
+    [0xa5, 0xc5, 0xb9, 0x40],
+    [0x9b, 0xe9, 0xe8, 0x8c],
+    [0x7d, 0xbc, 0x81, 0x40],
+    [0x7c, 0x07, 0x8e, 0xc5],
+    [0xd4, 0xe7, 0x6c, 0x73],
+    [0x42, 0x8f, 0xcb, 0xb9],
+    [0xbd, 0x83, 0x99, 0x7a],
+    [0x59, 0xea, 0x4a, 0x74],
+];
+
+/// `vectors_hsip64` from the SipHash reference implementation
+pub const HALFSIPHASH_2_4_64: [[u8; 8]; 64] = [
+    [0x21, 0x8d, 0x1f, 0x59, 0xb9, 0xb8, 0x3c, 0xc8],
+    [0xbe, 0x55, 0x24, 0x12, 0xf8, 0x38, 0x73, 0x15],
+    [0x06, 0x4f, 0x39, 0xef, 0x7c, 0x50, 0xeb, 0x57],
+    [0xce, 0x0f, 0x1a, 0x45, 0xf7, 0x06, 0x06, 0x79],
+    [0xd5, 0xe7, 0x8a, 0x17, 0x5b, 0xe5, 0x2e, 0xa1],
+    [0xcb, 0x9d, 0x7c, 0x3f, 0x2f, 0x3d, 0xb5, 0x80],
+    [0xce, 0x3e, 0x91, 0x35, 0x8a, 0xa2, 0xbc, 0x25],
+    [0xff, 0x20, 0x27, 0x28, 0xb0, 0x7b, 0xc6, 0x84],
+    [0xed, 0xfe, 0xe8, 0x20, 0xbc, 0xe4, 0x85, 0x8c],
+    [0x5b, 0x51, 0xcc, 0xcc, 0x13, 0x88, 0x83, 0x07],
+    [0x95, 0xb0, 0x46, 0x9f, 0x06, 0xa6, 0xf2, 0xee],
+    [0xae, 0x26, 0x33, 0x39, 0x94, 0xdd, 0xcd, 0x48],
+    [0x7b, 0xc7, 0x1f, 0x9f, 0xae, 0xf5, 0xc7, 0x99],
+    [0x5a, 0x23, 0x52, 0xd7, 0x5a, 0x0c, 0x37, 0x44],
+    [0x3b, 0xb1, 0xa8, 0x70, 0xea, 0xe8, 0xe6, 0x58],
+    [0x21, 0x7d, 0x0b, 0xcb, 0x4e, 0x81, 0xc9, 0x02],
+    [0x73, 0x36, 0xaa, 0xd2, 0x5f, 0x7b, 0xf3, 0xb5],
+    [0x37, 0xad, 0xc0, 0x64, 0x1c, 0x4c, 0x4f, 0x6a],
+    [0xc9, 0xb2, 0xdb, 0x2b, 0x9a, 0x3e, 0x42, 0xf9],
+    [0xf9, 0x10, 0xe4, 0x80, 0x20, 0xab, 0x36, 0x3c],
+    [0x1b, 0xf5, 0x2b, 0x0a, 0x6f, 0xee, 0xa7, 0xdb],
+    [0x00, 0x74, 0x1d, 0xc2, 0x69, 0xe8, 0xb3, 0xef],
+    [0xe2, 0x01, 0x03, 0xfa, 0x1b, 0xa7, 0x76, 0xef],
+    [0x4c, 0x22, 0x10, 0xe5, 0x4b, 0x68, 0x1d, 0x73],
+    [0x70, 0x74, 0x10, 0x45, 0xae, 0x3f, 0xa6, 0xf1],
+    [0x0c, 0x86, 0x40, 0x37, 0x39, 0x71, 0x40, 0x38],
+    [0x0d, 0x89, 0x9e, 0xd8, 0x11, 0x29, 0x23, 0xf0],
+    [0x22, 0x6b, 0xf5, 0xfa, 0xb8, 0x1e, 0xe1, 0xb8],
+    [0x2d, 0x92, 0x5f, 0xfb, 0x1e, 0x00, 0x16, 0xb5],
+    [0x36, 0x19, 0x58, 0xd5, 0x2c, 0xee, 0x10, 0xf1],
+    [0x29, 0x1a, 0xaf, 0x86, 0x48, 0x98, 0x17, 0x9d],
+    [0x86, 0x3c, 0x7f, 0x15, 0x5c, 0x34, 0x11, 0x7c],
+    [0x28, 0x70, 0x9d, 0x46, 0xd8, 0x11, 0x62, 0x6c],
+    [0x24, 0x84, 0x77, 0x68, 0x1d, 0x28, 0xf8, 0x9c],
+    [0x83, 0x24, 0xe4, 0xd7, 0x52, 0x8f, 0x98, 0x30],
+    [0xf9, 0xef, 0xd4, 0xe1, 0x3a, 0xea, 0x6b, 0xd8],
+    [0x86, 0xd6, 0x7a, 0x40, 0xec, 0x42, 0x76, 0xdc],
+    [0x3f, 0x62, 0x92, 0xec, 0xcc, 0xa9, 0x7e, 0x35],
+    [0xcb, 0xd9, 0x2e, 0xe7, 0x24, 0xd4, 0x21, 0x09],
+    [0x36, 0x8d, 0xf6, 0x80, 0x8d, 0x40, 0x3d, 0x79],
+    [0x5b, 0x38, 0xc8, 0x1c, 0x67, 0xc8, 0xae, 0x4c],
+    [0x95, 0xab, 0x71, 0x89, 0xd4, 0x39, 0xac, 0xb3],
+    [0xa9, 0x1a, 0x52, 0xc0, 0x25, 0x32, 0x70, 0x24],
+    [0x5b, 0x00, 0x87, 0xc6, 0x95, 0x28, 0xac, 0xea],
+    [0x1e, 0x30, 0xf3, 0xad, 0x27, 0xdc, 0xb1, 0x5a],
+    [0x69, 0x7f, 0x5c, 0x9a, 0x90, 0x32, 0x4e, 0xd4],
+    [0x49, 0x5c, 0x0f, 0x99, 0x55, 0x57, 0xdc, 0x38],
+    [0x94, 0x27, 0x20, 0x2a, 0x3c, 0x29, 0xf9, 0x4d],
+    [0xa9, 0xea, 0xa8, 0xc0, 0x4b, 0xa9, 0x3e, 0x3e],
+    [0xee, 0xa4, 0xc1, 0x73, 0x7d, 0x01, 0x12, 0x18],
+    [0x91, 0x2d, 0x56, 0x8f, 0xd8, 0xf6, 0x5a, 0x49],
+    [0x56, 0x91, 0x95, 0x96, 0xb0, 0xff, 0x5c, 0x97],
+    [0x02, 0x44, 0x5a, 0x79, 0x98, 0xf5, 0x50, 0xe1],
+    [0x86, 0xec, 0x46, 0x6c, 0xe7, 0x1d, 0x1f, 0xb2],
+    [0x35, 0x95, 0x69, 0xe7, 0xd2, 0x89, 0xe3, 0xbc],
+    [0x87, 0x1b, 0x05, 0xca, 0x62, 0xbb, 0x7c, 0x96],
+    [0xa1, 0xa4, 0x92, 0xf9, 0x42, 0xf1, 0x5f, 0x1d],
+    [0x12, 0xec, 0x26, 0x7f, 0xf6, 0x09, 0x5b, 0x6e],
+    [0x5d, 0x1b, 0x5e, 0xa1, 0xb2, 0x31, 0xd8, 0x9d],
+    [0xd8, 0xcf, 0xb4, 0x45, 0x3f, 0x92, 0xee, 0x54],
+    [0xd6, 0x76, 0x28, 0x90, 0xbf, 0x26, 0xe4, 0x60],
+    [0x31, 0x35, 0x63, 0xa4, 0xb7, 0xed, 0x5c, 0xf3],
+    [0xf9, 0x0b, 0x3a, 0xb5, 0x72, 0xd4, 0x66, 0x93],
+    [0x2e, 0xa6, 0x3c, 0x71, 0xbf, 0x32, 0x60, 0x87],
+];